@@ -0,0 +1,129 @@
+use term::Term;
+use variable::Variable;
+
+/// A fluent interface for constructing terms.
+///
+/// Builders start from a constant, a variable, or an existing term, and are then combined and wrapped in functions by chained method calls, finishing with `TermBuilder::build`. Sums and products are built with the `+` and `*` operators on `Term`, so they are flattened in the same way.
+///
+/// # Examples
+/// ```
+/// use cassie::{Term, TermBuilder, Variable};
+/// use std::collections::HashMap;
+///
+/// let t = TermBuilder::variable('x').mul(TermBuilder::constant(2.0)).sin().build();
+/// let x = Term::Variable(Variable::named('x'));
+/// assert_eq!(t, Term::Sine(Box::new(x * Term::Constant(2.0))));
+///
+/// let mut values = HashMap::new();
+/// values.insert("x".to_string(), 0.25);
+/// assert!((t.evaluate(&values).unwrap() - 0.5f64.sin()).abs() < 0.00001);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TermBuilder {
+	term: Term
+}
+
+impl TermBuilder {
+	/// Starts building from an existing term.
+	pub fn term(term: Term) -> Self {
+		Self { term }
+	}
+	/// Starts building from the constant `value`.
+	pub fn constant(value: f64) -> Self {
+		Self::term(Term::Constant(value))
+	}
+	/// Starts building from the variable with symbol `symbol`.
+	pub fn variable<S: Into<String>>(symbol: S) -> Self {
+		Self::term(Term::Variable(Variable::named(symbol)))
+	}
+	/// Finishes building, producing the built term.
+	pub fn build(self) -> Term {
+		self.term
+	}
+	/// Adds another built term, as with the `+` operator.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, TermBuilder};
+	///
+	/// let s = TermBuilder::constant(1.0).add(TermBuilder::constant(2.0)).add(TermBuilder::constant(3.0)).build();
+	/// assert_eq!(s, Term::Constant(1.0) + Term::Constant(2.0) + Term::Constant(3.0));
+	/// assert!((s.reduce().unwrap() - 6.0).abs() < 0.00001);
+	/// ```
+	#[allow(clippy::should_implement_trait)]
+	pub fn add(self, another: TermBuilder) -> Self {
+		Self::term(self.term + another.term)
+	}
+	/// Subtracts another built term, as with the `-` operator.
+	#[allow(clippy::should_implement_trait)]
+	pub fn sub(self, another: TermBuilder) -> Self {
+		Self::term(self.term - another.term)
+	}
+	/// Multiplies by another built term, as with the `*` operator.
+	#[allow(clippy::should_implement_trait)]
+	pub fn mul(self, another: TermBuilder) -> Self {
+		Self::term(self.term * another.term)
+	}
+	/// Divides by another built term, as with the `/` operator.
+	#[allow(clippy::should_implement_trait)]
+	pub fn div(self, another: TermBuilder) -> Self {
+		Self::term(self.term / another.term)
+	}
+	/// Raises to the power of another built term.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::TermBuilder;
+	///
+	/// let p = TermBuilder::constant(2.0).pow(TermBuilder::constant(10.0)).build();
+	/// assert!((p.reduce().unwrap() - 1024.0).abs() < 0.00001);
+	/// ```
+	pub fn pow(self, exponent: TermBuilder) -> Self {
+		Self::term(Term::Power(Box::new(self.term), Box::new(exponent.term)))
+	}
+	/// Negates, as with the unary `-` operator.
+	#[allow(clippy::should_implement_trait)]
+	pub fn neg(self) -> Self {
+		Self::term(-self.term)
+	}
+	/// Takes the sine.
+	pub fn sin(self) -> Self {
+		Self::term(Term::Sine(Box::new(self.term)))
+	}
+	/// Takes the cosine.
+	pub fn cos(self) -> Self {
+		Self::term(Term::Cosine(Box::new(self.term)))
+	}
+	/// Takes the tangent.
+	pub fn tan(self) -> Self {
+		Self::term(Term::Tangent(Box::new(self.term)))
+	}
+	/// Raises `e` to the power of the built term.
+	pub fn exp(self) -> Self {
+		Self::term(Term::Exp(Box::new(self.term)))
+	}
+	/// Takes the natural logarithm.
+	pub fn ln(self) -> Self {
+		Self::term(Term::NaturalLog(Box::new(self.term)))
+	}
+	/// Takes the square root.
+	pub fn sqrt(self) -> Self {
+		Self::term(Term::SquareRoot(Box::new(self.term)))
+	}
+	/// Takes the absolute value.
+	pub fn abs(self) -> Self {
+		Self::term(Term::AbsoluteValue(Box::new(self.term)))
+	}
+}
+
+impl From<Term> for TermBuilder {
+	fn from(term: Term) -> Self {
+		Self::term(term)
+	}
+}
+
+impl From<TermBuilder> for Term {
+	fn from(builder: TermBuilder) -> Self {
+		builder.build()
+	}
+}
@@ -0,0 +1,447 @@
+use condition::Condition;
+use term::Term;
+use variable::Variable;
+use std::collections::HashMap;
+
+/// Wraps a term in a `Box`; the derivative rules below construct a lot of these.
+fn boxed(term: Term) -> Box<Term> {
+	Box::new(term)
+}
+
+/// Constructs the variable term with symbol `symbol`.
+fn variable(symbol: &str) -> Term {
+	Term::Variable(Variable::named(symbol))
+}
+
+/// Constructs `sqrt(1 - u^2)`, which appears in the derivatives of the inverse sine and cosine.
+fn complement_root(term: &Term) -> Term {
+	let square = Term::Power(boxed(term.clone()), boxed(Term::Constant(2.0)));
+	Term::SquareRoot(boxed(Term::Difference(vec!(Term::Constant(1.0), square))))
+}
+
+impl Term {
+	/// Symbolically differentiates a term with respect to the variable `with_respect_to`.
+	///
+	/// The usual rules (linearity, product, quotient, and chain rules, along with the known derivatives of the elementary functions) are applied mechanically, so the result is correct but generally unsimplified.
+	///
+	/// Factorials and binomial coefficients have no derivatives expressible as terms, and are differentiated to `NaN` (unless they do not depend on the variable at all, in which case their derivatives are zero). Floors, ceilings, signs, rounded terms, and integer parts are differentiated to zero, and fractional parts as the terms themselves, ignoring their discontinuities. Maxima, minima, and clamped terms are differentiated piecewise, taking the derivative of whichever term is selected. Unevaluated derivatives (see `Term::Derivative`) are computed before being differentiated again.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	/// use std::collections::HashMap;
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let square = Term::Power(Box::new(x.clone()), Box::new(Term::Constant(2.0)));
+	/// let derivative = square.derivative("x");
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 3.0);
+	/// assert!((derivative.evaluate(&values).unwrap() - 6.0).abs() < 0.00001);
+	///
+	/// let wave = Term::Sine(Box::new(x * Term::Constant(2.0)));
+	/// let derivative = wave.derivative("x");
+	/// values.insert("x".to_string(), 0.0);
+	/// assert!((derivative.evaluate(&values).unwrap() - 2.0).abs() < 0.00001);
+	/// ```
+	pub fn derivative(&self, with_respect_to: &str) -> Term {
+		use Term::*;
+		let d = |term: &Term| term.derivative(with_respect_to);
+		match *self {
+			Constant(_) => Constant(0.0),
+			Variable(ref variable) => Constant(if variable.symbol == with_respect_to { 1.0 } else { 0.0 }),
+			Sum(ref terms) => Sum(terms.iter().map(d).collect()),
+			Difference(ref terms) => Difference(terms.iter().map(d).collect()),
+			Product(ref terms) => {
+				Sum((0..terms.len()).map(|index| {
+					Product(terms.iter().enumerate().map(|(other, term)| {
+						if other == index { d(term) } else { term.clone() }
+					}).collect())
+				}).collect())
+			},
+			Quotient(ref terms) => {
+				if terms.len() < 2 {
+					return Quotient(terms.iter().map(d).collect());
+				}
+				let numerator = &terms[0];
+				let denominator = Product(terms[1..].to_vec());
+				let derivative = d(&denominator);
+				Quotient(vec!(
+					Difference(vec!(
+						Product(vec!(d(numerator), denominator.clone())),
+						Product(vec!(numerator.clone(), derivative))
+					)),
+					Power(boxed(denominator), boxed(Constant(2.0)))
+				))
+			},
+			Sine(ref term) => Product(vec!(Cosine(term.clone()), d(term))),
+			Cosine(ref term) => Negation(boxed(Product(vec!(Sine(term.clone()), d(term))))),
+			Tangent(ref term) => Quotient(vec!(d(term), Power(boxed(Cosine(term.clone())), boxed(Constant(2.0))))),
+			ArcSine(ref term) => Quotient(vec!(d(term), complement_root(term))),
+			ArcCosine(ref term) => Negation(boxed(Quotient(vec!(d(term), complement_root(term))))),
+			ArcTangent(ref term) => Quotient(vec!(d(term), Sum(vec!(Constant(1.0), Power(term.clone(), boxed(Constant(2.0))))))),
+			Power(ref base, ref exponent) => {
+				if !exponent.free_variables().contains(with_respect_to) {
+					// Power rule: (f^c)' = c * f^(c - 1) * f'
+					let reduced = match **exponent {
+						Constant(value) => Constant(value - 1.0),
+						_ => Difference(vec!((**exponent).clone(), Constant(1.0)))
+					};
+					Product(vec!((**exponent).clone(), Power(base.clone(), boxed(reduced)), d(base)))
+				} else if !base.free_variables().contains(with_respect_to) {
+					// Exponential rule: (c^g)' = c^g * ln(c) * g'
+					Product(vec!(self.clone(), NaturalLog(base.clone()), d(exponent)))
+				} else {
+					// General rule: (f^g)' = f^g * (g' * ln(f) + g * f' / f)
+					Product(vec!(self.clone(), Sum(vec!(
+						Product(vec!(d(exponent), NaturalLog(base.clone()))),
+						Quotient(vec!(Product(vec!((**exponent).clone(), d(base))), (**base).clone()))
+					))))
+				}
+			},
+			Exp(ref term) => Product(vec!(self.clone(), d(term))),
+			NaturalLog(ref term) => Quotient(vec!(d(term), (**term).clone())),
+			Logarithm(ref term, ref base) => {
+				// log_b(u) = ln(u) / ln(b)
+				Quotient(vec!(NaturalLog(term.clone()), NaturalLog(base.clone()))).derivative(with_respect_to)
+			},
+			SquareRoot(ref term) => Quotient(vec!(d(term), Product(vec!(Constant(2.0), self.clone())))),
+			NthRoot(ref term, ref index) => {
+				if index.free_variables().contains(with_respect_to) {
+					Power(term.clone(), boxed(Quotient(vec!(Constant(1.0), (**index).clone())))).derivative(with_respect_to)
+				} else {
+					// (u^(1/n))' = u^(1/n) / (n * u) * u'
+					Product(vec!(Quotient(vec!(self.clone(), Product(vec!((**index).clone(), (**term).clone())))), d(term)))
+				}
+			},
+			AbsoluteValue(ref term) => Product(vec!(Quotient(vec!((**term).clone(), self.clone())), d(term))),
+			Negation(ref term) => Negation(boxed(d(term))),
+			Sinh(ref term) => Product(vec!(Cosh(term.clone()), d(term))),
+			Cosh(ref term) => Product(vec!(Sinh(term.clone()), d(term))),
+			Tanh(ref term) => Quotient(vec!(d(term), Power(boxed(Cosh(term.clone())), boxed(Constant(2.0))))),
+			Secant(ref term) => Product(vec!(self.clone(), Tangent(term.clone()), d(term))),
+			Cosecant(ref term) => Negation(boxed(Product(vec!(self.clone(), Cotangent(term.clone()), d(term))))),
+			Cotangent(ref term) => Negation(boxed(Quotient(vec!(d(term), Power(boxed(Sine(term.clone())), boxed(Constant(2.0))))))),
+			Modulo(ref dividend, ref divisor) => {
+				// a mod b = a - b * q, where the quotient q = (a - a mod b) / b is piecewise constant
+				let quotient = Quotient(vec!(Difference(vec!((**dividend).clone(), self.clone())), (**divisor).clone()));
+				Difference(vec!(d(dividend), Product(vec!(d(divisor), quotient))))
+			},
+			Factorial(ref term) if !term.free_variables().contains(with_respect_to) => Constant(0.0),
+			Factorial(_) => {
+				// The derivative of the gamma function involves the digamma function, which has no representation as a term.
+				Constant(f64::NAN)
+			},
+			Summation { ref index, ref from, ref to, ref body } => {
+				if index == with_respect_to {
+					// The variable is shadowed by the index throughout the body.
+					Constant(0.0)
+				} else {
+					Summation { index: index.clone(), from: from.clone(), to: to.clone(), body: boxed(d(body)) }
+				}
+			},
+			IndexedProduct { ref index, ref from, ref to, ref body } => {
+				if index == with_respect_to {
+					Constant(0.0)
+				} else {
+					// Logarithmic differentiation: (Π f)' = Π f * Σ f' / f
+					let logarithmic = Summation { index: index.clone(), from: from.clone(), to: to.clone(), body: boxed(Quotient(vec!(d(body), (**body).clone()))) };
+					Product(vec!(self.clone(), logarithmic))
+				}
+			},
+			Pi | E => Constant(0.0),
+			Piecewise(ref branches) => Piecewise(branches.iter().map(|(condition, term)| (condition.clone(), d(term))).collect()),
+			Floor(_) | Ceiling(_) | Sign(_) | Round(_) | Truncate(_) => {
+				// These are all piecewise constant, so their derivatives vanish wherever they are defined.
+				Constant(0.0)
+			},
+			Maximum(ref terms) => {
+				// The derivative is that of whichever term is greatest.
+				Piecewise(terms.iter().map(|term| (Condition::GreaterThanOrEqual(term.clone(), self.clone()), d(term))).collect())
+			},
+			Minimum(ref terms) => {
+				// The derivative is that of whichever term is least.
+				Piecewise(terms.iter().map(|term| (Condition::LessThanOrEqual(term.clone(), self.clone()), d(term))).collect())
+			},
+			Fractional(ref term) => {
+				// The fractional part differs from the term itself by a piecewise constant.
+				d(term)
+			},
+			Clamp { ref value, ref min, ref max } => {
+				// The derivative is that of whichever term the value is clamped to.
+				Piecewise(vec!(
+					(Condition::LessThan((**value).clone(), (**min).clone()), d(min)),
+					(Condition::LessThanOrEqual((**value).clone(), (**max).clone()), d(value)),
+					(Condition::GreaterThan((**value).clone(), (**max).clone()), d(max))
+				))
+			},
+			PowerInt(ref base, exponent) => {
+				if exponent == 0 {
+					return Constant(0.0);
+				}
+				// Power rule: (f^n)' = n * f^(n - 1) * f'
+				Product(vec!(Constant(f64::from(exponent)), PowerInt(base.clone(), exponent - 1), d(base)))
+			},
+			Derivative { ref body, ref variable } => body.derivative(variable).derivative(with_respect_to),
+			Atan2 { ref y, ref x } => {
+				// (atan2(y, x))' = (x * y' - y * x') / (x^2 + y^2)
+				let square = |term: &Term| Power(boxed(term.clone()), boxed(Constant(2.0)));
+				Quotient(vec!(
+					Difference(vec!(Product(vec!((**x).clone(), d(y))), Product(vec!((**y).clone(), d(x))))),
+					Sum(vec!(square(x), square(y)))
+				))
+			},
+			Sigmoid(ref term) => {
+				// (σ(f))' = σ(f) * (1 - σ(f)) * f'
+				Product(vec!(self.clone(), Difference(vec!(Constant(1.0), self.clone())), d(term)))
+			},
+			ToRadians(ref term) => ToRadians(boxed(d(term))),
+			ToDegrees(ref term) => ToDegrees(boxed(d(term))),
+			Binomial { ref n, ref k } if !n.free_variables().contains(with_respect_to) && !k.free_variables().contains(with_respect_to) => Constant(0.0),
+			Binomial { .. } => {
+				// Like the factorial, the binomial coefficient is only defined for integers.
+				Constant(f64::NAN)
+			}
+		}
+	}
+
+	/// Computes the gradient of a term: its partial derivative (see `Term::derivative`) with respect to each of its free variables (see `Term::free_variables`).
+	///
+	/// Variables which do not appear free in the term have no entry, rather than a zero partial derivative.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x * y".parse().unwrap();
+	/// let gradient = t.gradient();
+	/// assert_eq!(gradient.len(), 2);
+	/// assert!(gradient.get("z").is_none());
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// values.insert("y".to_string(), 5.0);
+	/// assert!((gradient["x"].evaluate(&values).unwrap() - 5.0).abs() < 0.00001);
+	/// assert!((gradient["y"].evaluate(&values).unwrap() - 2.0).abs() < 0.00001);
+	/// ```
+	pub fn gradient(&self) -> HashMap<String, Term> {
+		self.free_variables().into_iter().map(|symbol| {
+			let partial = self.derivative(&symbol);
+			(symbol, partial)
+		}).collect()
+	}
+
+	/// Computes the Hessian of a term: its second partial derivative with respect to each ordered pair of its free variables.
+	///
+	/// The entry for `(a, b)` is the partial derivative with respect to `b` of the partial derivative with respect to `a` (see `Term::gradient`). Both `(a, b)` and `(b, a)` are computed separately, even though they agree for the smooth terms usually encountered.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x^2 * y".parse().unwrap();
+	/// let hessian = t.hessian();
+	/// assert_eq!(hessian.len(), 4);
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 3.0);
+	/// values.insert("y".to_string(), 5.0);
+	/// let entry = |a: &str, b: &str| hessian[&(a.to_string(), b.to_string())].evaluate(&values).unwrap();
+	/// assert!((entry("x", "x") - 10.0).abs() < 0.00001);
+	/// assert!((entry("x", "y") - 6.0).abs() < 0.00001);
+	/// assert!((entry("y", "x") - 6.0).abs() < 0.00001);
+	/// assert!(entry("y", "y").abs() < 0.00001);
+	/// ```
+	pub fn hessian(&self) -> HashMap<(String, String), Term> {
+		let symbols = self.free_variables();
+		self.gradient().into_iter().flat_map(|(a, partial)| {
+			symbols.iter().map(move |b| {
+				let second = partial.derivative(b);
+				((a.clone(), b.clone()), second)
+			})
+		}).collect()
+	}
+
+	/// Symbolically integrates a term with respect to the variable `with_respect_to`, producing an antiderivative (without a constant of integration).
+	///
+	/// Only a few basic rules are known: linearity (over sums, differences, negations, angle conversions, and constant factors or divisors), the power rule (including `1/x` and `x^-1`, which integrate to `ln(abs(x))`), exponentials with constant bases, and the antiderivatives of the sine, cosine, exponential, and sigmoid functions (whose arguments may be any linear function of the variable). Integrands which cannot be handled by these rules produce an error, rather than a wrong answer.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "2 * x".parse().unwrap();
+	/// let integral = t.integrate("x").unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 3.0);
+	/// assert!((integral.evaluate(&values).unwrap() - 9.0).abs() < 0.00001);
+	///
+	/// let t: Term = "3 * x^2 - cos(2 * x) + 1 / x".parse().unwrap();
+	/// let integral = t.integrate("x").unwrap();
+	/// assert!((integral.derivative("x").evaluate(&values).unwrap() - t.evaluate(&values).unwrap()).abs() < 0.00001);
+	///
+	/// let t: Term = "x * sin(x)".parse().unwrap();
+	/// assert!(t.integrate("x").is_err());
+	/// ```
+	pub fn integrate(&self, with_respect_to: &str) -> Result<Term, String> {
+		use Term::*;
+		let x = || variable(with_respect_to);
+		let is_x = |term: &Term| match *term {
+			Variable(ref variable) => variable.symbol == with_respect_to,
+			_ => false
+		};
+		let constant = |term: &Term| !term.free_variables().contains(with_respect_to);
+		let integrate = |term: &Term| term.integrate(with_respect_to);
+		let unsupported = || Err(format!("Unable to integrate {} with respect to {}.", self, with_respect_to));
+		// Integrates f(u) given the antiderivative F(u) of f, where u is linear in the variable, by dividing F(u) by the slope of u.
+		let linear = |argument: &Term, antiderivative: Term| {
+			let slope = argument.derivative(with_respect_to).simplify();
+			if !constant(&slope) {
+				return unsupported();
+			}
+			Ok(if slope.is_one() { antiderivative } else { Quotient(vec!(antiderivative, slope)) })
+		};
+		if constant(self) {
+			return Ok(Product(vec!(self.clone(), x())));
+		}
+		match *self {
+			Variable(_) => Ok(Quotient(vec!(Power(boxed(x()), boxed(Constant(2.0))), Constant(2.0)))),
+			Sum(ref terms) => Ok(Sum(terms.iter().map(integrate).collect::<Result<_, _>>()?)),
+			Difference(ref terms) => Ok(Difference(terms.iter().map(integrate).collect::<Result<_, _>>()?)),
+			Negation(ref term) => Ok(Negation(boxed(integrate(term)?))),
+			Product(ref terms) => {
+				let (constants, dependent): (Vec<&Term>, Vec<&Term>) = terms.iter().partition(|term| constant(term));
+				if dependent.len() != 1 {
+					return unsupported();
+				}
+				Ok(Product(constants.into_iter().cloned().chain(Some(integrate(dependent[0])?)).collect()))
+			},
+			Quotient(ref terms) => {
+				if terms[1..].iter().all(&constant) {
+					let mut terms = terms.clone();
+					terms[0] = integrate(&terms[0])?;
+					Ok(Quotient(terms))
+				} else if terms.len() == 2 && constant(&terms[0]) && is_x(&terms[1]) {
+					Ok(Product(vec!(terms[0].clone(), NaturalLog(boxed(AbsoluteValue(boxed(x())))))))
+				} else {
+					unsupported()
+				}
+			},
+			Power(ref base, ref exponent) if is_x(base) && constant(exponent) => {
+				if let Constant(value) = **exponent {
+					if value == -1.0 {
+						return Ok(NaturalLog(boxed(AbsoluteValue(boxed(x())))));
+					}
+				}
+				let raised = match **exponent {
+					Constant(value) => Constant(value + 1.0),
+					_ => Sum(vec!((**exponent).clone(), Constant(1.0)))
+				};
+				Ok(Quotient(vec!(Power(base.clone(), boxed(raised.clone())), raised)))
+			},
+			Derivative { ref body, ref variable } if variable == with_respect_to => Ok((**body).clone()),
+			PowerInt(ref base, exponent) => Power(base.clone(), boxed(Constant(f64::from(exponent)))).integrate(with_respect_to),
+			Power(ref base, ref exponent) if constant(base) => linear(exponent, Quotient(vec!(self.clone(), NaturalLog(base.clone())))),
+			Sine(ref term) => linear(term, Negation(boxed(Cosine(term.clone())))),
+			Cosine(ref term) => linear(term, Sine(term.clone())),
+			Exp(ref term) => linear(term, self.clone()),
+			ToRadians(ref term) => Ok(ToRadians(boxed(integrate(term)?))),
+			ToDegrees(ref term) => Ok(ToDegrees(boxed(integrate(term)?))),
+			Sigmoid(ref term) => linear(term, NaturalLog(boxed(Sum(vec!(Constant(1.0), Exp(term.clone())))))),
+			_ => unsupported()
+		}
+	}
+
+	/// Computes the Taylor polynomial of a term, as a function of the variable `symbol`, of degree `order` around `center`.
+	///
+	/// The term is differentiated `order` times (symbolically, simplifying as it goes), and each derivative is evaluated at `center` to produce the polynomial `f(c) + f'(c) (x - c) + f''(c) / 2! (x - c)^2 + ...`; terms with zero coefficients are omitted. An error is returned if any of the derivatives cannot be evaluated at `center` (for example, because the term involves other variables).
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "exp(x)".parse().unwrap();
+	/// let series = t.taylor_series("x", 0.0, 3).unwrap();
+	/// assert_eq!(&format!("{}", series), "1 + x + 0.5 * x^2 + 0.16666666666666666 * x^3");
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 0.1);
+	/// assert!((series.evaluate(&values).unwrap() - 0.1f64.exp()).abs() < 0.00001);
+	///
+	/// let t: Term = "ln(x)".parse().unwrap();
+	/// let series = t.taylor_series("x", 1.0, 2).unwrap();
+	/// assert_eq!(&format!("{}", series), "x - 1 + -0.5 * (x - 1)^2");
+	/// assert!(t.taylor_series("x", 0.0, 2).is_err());
+	/// ```
+	pub fn taylor_series(&self, symbol: &str, center: f64, order: usize) -> Result<Term, String> {
+		let displacement = if center == 0.0 {
+			variable(symbol)
+		} else {
+			Term::Difference(vec!(variable(symbol), Term::Constant(center)))
+		};
+		let mut derivative = self.simplify();
+		let mut factorial = 1.0;
+		let mut terms = vec!();
+		for power in 0..=order {
+			if power > 0 {
+				derivative = derivative.derivative(symbol).simplify();
+				factorial *= power as f64;
+			}
+			let coefficient = derivative.evaluate_at(symbol, center)? / factorial;
+			if coefficient == 0.0 {
+				continue;
+			}
+			let monomial = match power {
+				0 => None,
+				1 => Some(displacement.clone()),
+				_ => Some(Term::Power(boxed(displacement.clone()), boxed(Term::Constant(power as f64))))
+			};
+			terms.push(match monomial {
+				None => Term::Constant(coefficient),
+				Some(monomial) if coefficient == 1.0 => monomial,
+				Some(monomial) => Term::Product(vec!(Term::Constant(coefficient), monomial))
+			});
+		}
+		Ok(match terms.len() {
+			0 => Term::zero(),
+			1 => terms.remove(0),
+			_ => Term::Sum(terms)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Term;
+	use std::collections::HashMap;
+
+	#[test]
+	fn constant_factorial_derivative() {
+		let term: Term = "factorial(3) * x".parse().unwrap();
+		let derivative = term.derivative("x");
+		let mut values = HashMap::new();
+		values.insert("x".to_string(), 2.0);
+		assert_eq!(derivative.evaluate(&values), Ok(6.0));
+		assert!(term.find_root("x", 1.0, 0.000001, 100).unwrap().abs() < 0.000001);
+
+		let term: Term = "factorial(x)".parse().unwrap();
+		assert!(term.derivative("x").evaluate(&values).unwrap().is_nan());
+	}
+
+	#[test]
+	fn constant_binomial_derivative() {
+		let term: Term = "binomial(5, 2) * x".parse().unwrap();
+		let derivative = term.derivative("x");
+		let mut values = HashMap::new();
+		values.insert("x".to_string(), 2.0);
+		assert_eq!(derivative.evaluate(&values), Ok(10.0));
+		assert_eq!(derivative.simplify(), Term::Constant(10.0));
+
+		let term: Term = "binomial(x, 2)".parse().unwrap();
+		assert!(term.derivative("x").evaluate(&values).unwrap().is_nan());
+	}
+}
@@ -0,0 +1,180 @@
+use error::EvalError;
+use num_complex::Complex64;
+use std::collections::HashMap;
+use term::{AngleMode, Term, DIVISION_EPSILON, POLE_EPSILON};
+
+type ComplexValues = HashMap<String, Complex64>;
+
+/// Extracts the real value of `value` for `function`, which requires one.
+fn real(function: &str, value: Complex64) -> Result<f64, EvalError> {
+	if value.im == 0.0 {
+		Ok(value.re)
+	} else {
+		Err(EvalError::NonReal(function.to_string()))
+	}
+}
+
+/// Ensures that `value` is not (approximately) zero, since it is about to be divided by.
+fn nonzero(value: Complex64, epsilon: f64) -> Result<Complex64, EvalError> {
+	if value.norm() < epsilon {
+		Err(EvalError::DivisionByZero)
+	} else {
+		Ok(value)
+	}
+}
+
+impl Term {
+	/// Evaluates a term over the complex numbers, given the (complex) values of its variables.
+	///
+	/// Every operation is carried out in `Complex64` arithmetic, so functions which would be undefined for some real arguments (such as the square root or logarithm of a negative number, or the inverse sine of a number greater than one) instead produce their principal complex values. In particular, roots are always principal roots, so the cube root of `-8` is `1 + 1.732i` rather than `-2`. Angles are measured in radians.
+	///
+	/// A few operations remain defined only for real numbers: moduli, factorials, floors, ceilings, rounding, integer and fractional parts, signs, maxima, minima, and clamping, the bounds of summations and indexed products, and the comparisons of piecewise terms. Given non-real values, these produce `EvalError::NonReal`. Division by zero (including at the poles of the reciprocal trigonometric functions) and the logarithm of zero are still errors.
+	///
+	/// This method is only available with the `complex` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Complex64, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let i = Term::SquareRoot(Box::new(Term::Constant(-1.0)));
+	/// assert!(i.reduce().is_err());
+	/// let value = i.evaluate_complex(&HashMap::new()).unwrap();
+	/// assert!((value - Complex64::new(0.0, 1.0)).norm() < 0.00001);
+	///
+	/// let t: Term = "exp(x * pi)".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), Complex64::new(0.0, 1.0));
+	/// assert!((t.evaluate_complex(&values).unwrap() - Complex64::new(-1.0, 0.0)).norm() < 0.00001);
+	/// ```
+	pub fn evaluate_complex(&self, values: &ComplexValues) -> Result<Complex64, EvalError> {
+		self.evaluate_postorder(|term, operands| term.apply_complex(&operands, values))
+	}
+
+	/// Computes the complex value of a term given the values of its children, in order (see `Term::apply`).
+	fn apply_complex(&self, operands: &[Complex64], values: &ComplexValues) -> Result<Complex64, EvalError> {
+		use Term::*;
+		if let Some((index, from, to, body)) = self.indexed() {
+			let summation = matches!(*self, Summation { .. });
+			let function = if summation { "summation" } else { "product" };
+			let bound = |term: &Term| -> Result<f64, EvalError> {
+				let value = real(&format!("{} bound", function), term.evaluate_complex(values)?)?;
+				if value.fract() != 0.0 {
+					return Err(EvalError::DomainError { function: format!("{} bound", function), value });
+				}
+				Ok(value)
+			};
+			let (mut value, to) = (bound(from)?, bound(to)?);
+			let mut bindings = values.clone();
+			let mut result = Complex64::from(if summation { 0.0 } else { 1.0 });
+			while value <= to {
+				bindings.insert(index.to_string(), Complex64::from(value));
+				let term = body.evaluate_complex(&bindings)?;
+				result = if summation { result + term } else { result * term };
+				value += 1.0;
+			}
+			return Ok(result);
+		}
+		match *self {
+			Variable(ref variable) => values.get(&variable.symbol).cloned().ok_or_else(|| EvalError::UndefinedVariable(variable.symbol.clone())),
+			Constant(_) | Pi | E => Ok(Complex64::from(self.apply(&[], None, AngleMode::Radians)?)),
+			Sum(_) => Ok(operands.iter().sum()),
+			Difference(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("difference".to_string()))?;
+				Ok(rest.iter().fold(*first, |difference, value| difference - value))
+			},
+			Product(_) => Ok(operands.iter().product()),
+			Quotient(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("quotient".to_string()))?;
+				rest.iter().try_fold(*first, |quotient, &divisor| Ok(quotient / nonzero(divisor, DIVISION_EPSILON)?))
+			},
+			Sine(_) => Ok(operands[0].sin()),
+			Cosine(_) => Ok(operands[0].cos()),
+			Tangent(_) => Ok(operands[0].tan()),
+			ArcSine(_) => Ok(operands[0].asin()),
+			ArcCosine(_) => Ok(operands[0].acos()),
+			ArcTangent(_) => Ok(operands[0].atan()),
+			Power(_, _) => {
+				let (base, exponent) = (operands[0], operands[1]);
+				// Real powers with real results are computed exactly as they would be over the reals.
+				if base.im == 0.0 && exponent.im == 0.0 && (base.re >= 0.0 || exponent.re.fract() == 0.0) {
+					Ok(Complex64::from(base.re.powf(exponent.re)))
+				} else {
+					Ok(base.powc(exponent))
+				}
+			},
+			PowerInt(_, exponent) => {
+				let base = operands[0];
+				if exponent < 0 {
+					nonzero(base, DIVISION_EPSILON)?;
+				}
+				Ok(base.powi(exponent))
+			},
+			Exp(_) => Ok(operands[0].exp()),
+			ToRadians(_) => Ok(operands[0] * 1f64.to_radians()),
+			ToDegrees(_) => Ok(operands[0] * 1f64.to_degrees()),
+			Sigmoid(_) => Ok(Complex64::from(1.0) / (Complex64::from(1.0) + (-operands[0]).exp())),
+			NaturalLog(_) => {
+				if operands[0].norm() == 0.0 {
+					return Err(EvalError::DomainError { function: "ln".to_string(), value: 0.0 });
+				}
+				Ok(operands[0].ln())
+			},
+			Logarithm(_, _) => {
+				let (value, base) = (operands[0], operands[1]);
+				if value.norm() == 0.0 {
+					return Err(EvalError::DomainError { function: "log".to_string(), value: 0.0 });
+				}
+				if base.norm() == 0.0 || base == Complex64::from(1.0) {
+					return Err(EvalError::DomainError { function: "log base".to_string(), value: base.re });
+				}
+				Ok(value.ln() / base.ln())
+			},
+			SquareRoot(_) => Ok(operands[0].sqrt()),
+			NthRoot(_, _) => {
+				let (value, index) = (operands[0], operands[1]);
+				if index.norm() == 0.0 {
+					return Err(EvalError::DomainError { function: "root index".to_string(), value: 0.0 });
+				}
+				Ok(value.powc(index.inv()))
+			},
+			AbsoluteValue(_) => Ok(Complex64::from(operands[0].norm())),
+			Negation(_) => Ok(-operands[0]),
+			Sinh(_) => Ok(operands[0].sinh()),
+			Cosh(_) => Ok(operands[0].cosh()),
+			Tanh(_) => Ok(operands[0].tanh()),
+			Secant(_) => Ok(nonzero(operands[0].cos(), POLE_EPSILON)?.inv()),
+			Cosecant(_) => Ok(nonzero(operands[0].sin(), POLE_EPSILON)?.inv()),
+			Cotangent(_) => Ok(operands[0].cos() / nonzero(operands[0].sin(), POLE_EPSILON)?),
+			Modulo(_, _) => {
+				let operands = [real("mod", operands[0])?, real("mod", operands[1])?];
+				Ok(Complex64::from(self.apply(&operands, None, AngleMode::Radians)?))
+			},
+			Factorial(_) => Ok(Complex64::from(self.apply(&[real("factorial", operands[0])?], None, AngleMode::Radians)?)),
+			Derivative { ref body, ref variable } => body.derivative(variable).evaluate_complex(values),
+			Piecewise(ref branches) => {
+				for (condition, term) in branches {
+					let (a, b) = condition.operands();
+					let (a, b) = (real("comparison", a.evaluate_complex(values)?)?, real("comparison", b.evaluate_complex(values)?)?);
+					if condition.compare(a, b) {
+						return term.evaluate_complex(values);
+					}
+				}
+				Err(EvalError::NoMatchingBranch)
+			},
+			Floor(_) => Ok(Complex64::from(real("floor", operands[0])?.floor())),
+			Ceiling(_) => Ok(Complex64::from(real("ceil", operands[0])?.ceil())),
+			Sign(_) => Ok(Complex64::from(self.apply(&[real("sign", operands[0])?], None, AngleMode::Radians)?)),
+			Round(_) | Truncate(_) | Fractional(_) => {
+				let function = self.function_name().unwrap_or_default();
+				Ok(Complex64::from(self.apply(&[real(function, operands[0])?], None, AngleMode::Radians)?))
+			},
+			Maximum(_) | Minimum(_) | Clamp { .. } | Atan2 { .. } | Binomial { .. } => {
+				let function = self.function_name().unwrap_or_default();
+				let operands = operands.iter().map(|&operand| real(function, operand)).collect::<Result<Vec<_>, _>>()?;
+				Ok(Complex64::from(self.apply(&operands, None, AngleMode::Radians)?))
+			},
+			Summation { .. } | IndexedProduct { .. } => unreachable!()
+		}
+	}
+}
@@ -0,0 +1,99 @@
+use error::EvalError;
+use std::fmt;
+use term::{AngleMode, Term, VariableValues};
+
+/// A comparison between two terms, used to select a branch of a `Term::Piecewise`.
+///
+/// Both terms are evaluated (with the same variable values as the piecewise term containing the condition), and their values compared exactly.
+///
+/// # Examples
+/// ```
+/// use cassie::{Condition, Term};
+///
+/// let c = Condition::GreaterThanOrEqual(Term::from('x'), Term::zero());
+/// assert_eq!(&format!("{}", c), "x >= 0");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Condition {
+	/// Holds if the first term is greater than the second.
+	GreaterThan(Term, Term),
+	/// Holds if the first term is greater than or equal to the second.
+	GreaterThanOrEqual(Term, Term),
+	/// Holds if the first term is less than the second.
+	LessThan(Term, Term),
+	/// Holds if the first term is less than or equal to the second.
+	LessThanOrEqual(Term, Term),
+	/// Holds if the two terms are equal.
+	Equal(Term, Term),
+	/// Holds if the two terms are not equal.
+	NotEqual(Term, Term)
+}
+
+impl Condition {
+	/// Returns the two terms being compared, in order.
+	pub(crate) fn operands(&self) -> (&Term, &Term) {
+		use self::Condition::*;
+		match *self {
+			GreaterThan(ref a, ref b) | GreaterThanOrEqual(ref a, ref b) | LessThan(ref a, ref b) |
+			LessThanOrEqual(ref a, ref b) | Equal(ref a, ref b) | NotEqual(ref a, ref b) => (a, b)
+		}
+	}
+
+	/// Rebuilds a condition with `f` applied to each of the terms being compared.
+	pub(crate) fn map<F: FnMut(&Term) -> Term>(&self, mut f: F) -> Condition {
+		use self::Condition::*;
+		let (a, b) = self.operands();
+		let (a, b) = (f(a), f(b));
+		match *self {
+			GreaterThan(_, _) => GreaterThan(a, b),
+			GreaterThanOrEqual(_, _) => GreaterThanOrEqual(a, b),
+			LessThan(_, _) => LessThan(a, b),
+			LessThanOrEqual(_, _) => LessThanOrEqual(a, b),
+			Equal(_, _) => Equal(a, b),
+			NotEqual(_, _) => NotEqual(a, b)
+		}
+	}
+
+	/// The symbol of the comparison, as displayed between its operands.
+	pub(crate) fn symbol(&self) -> &'static str {
+		use self::Condition::*;
+		match *self {
+			GreaterThan(_, _) => ">",
+			GreaterThanOrEqual(_, _) => ">=",
+			LessThan(_, _) => "<",
+			LessThanOrEqual(_, _) => "<=",
+			Equal(_, _) => "==",
+			NotEqual(_, _) => "!="
+		}
+	}
+
+	/// Evaluates both terms and determines whether the condition holds between their values, treating divisors as zero if they are within `epsilon` of it.
+	pub(crate) fn holds(&self, values: Option<&VariableValues>, mode: AngleMode, epsilon: f64) -> Result<bool, EvalError> {
+		let (a, b) = self.operands();
+		Ok(self.compare(a.eval_with_epsilon(values, mode, epsilon)?, b.eval_with_epsilon(values, mode, epsilon)?))
+	}
+
+	/// Determines whether the condition holds between the values of its two terms.
+	pub(crate) fn compare<T: PartialOrd>(&self, a: T, b: T) -> bool {
+		use self::Condition::*;
+		match *self {
+			GreaterThan(_, _) => a > b,
+			GreaterThanOrEqual(_, _) => a >= b,
+			LessThan(_, _) => a < b,
+			LessThanOrEqual(_, _) => a <= b,
+			Equal(_, _) => a == b,
+			NotEqual(_, _) => a != b
+		}
+	}
+}
+
+impl fmt::Display for Condition {
+	/// Conditions are displayed as their two terms separated by a comparison operator (one of `>`, `>=`, `<`, `<=`, `==`, and `!=`).
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let (a, b) = self.operands();
+		fmt::Display::fmt(a, f)?;
+		write!(f, " {} ", self.symbol())?;
+		fmt::Display::fmt(b, f)
+	}
+}
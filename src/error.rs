@@ -0,0 +1,87 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors which may arise while evaluating a term.
+///
+/// # Examples
+/// ```
+/// use cassie::{EvalError, Term};
+///
+/// let q = Term::Constant(1.0) / Term::Constant(0.0);
+/// assert_eq!(q.reduce(), Err(EvalError::DivisionByZero));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalError {
+	/// A divisor evaluated to (approximately) zero.
+	DivisionByZero,
+	/// A variable was encountered for which no value was provided.
+	UndefinedVariable(String),
+	/// A variable was encountered, but no variable values were provided at all (as with `Term::reduce`).
+	NoBindings(String),
+	/// A function was applied to a value outside of its (real) domain.
+	///
+	/// `function` names the offending function (e.g. `"sqrt"`), and `value` is the argument it was given.
+	DomainError {
+		function: String,
+		value: f64
+	},
+	/// None of the conditions of a `Term::Piecewise` held.
+	NoMatchingBranch,
+	/// A function defined only for real numbers was given a non-real argument (as in `Term::evaluate_complex`).
+	///
+	/// The function's name (e.g. `"mod"`) is given.
+	NonReal(String),
+	/// A term whose value is generally irrational (such as a sine or square root) was encountered during exact evaluation (as in `Term::evaluate_rational`).
+	///
+	/// The name of the offending function or constant (e.g. `"sin"`) is given.
+	Irrational(String),
+	/// A function of any number of operands (such as `Term::Maximum`) was given none at all.
+	///
+	/// The function's name (e.g. `"max"`) is given.
+	NoOperands(String),
+	/// A variable had no value of its own, but could have taken any of several values differing from its symbol only by case (as in `Term::evaluate_case_insensitive`).
+	///
+	/// `symbol` is the variable's symbol, and `candidates` are the symbols of the matching values, in sorted order.
+	AmbiguousVariable {
+		symbol: String,
+		candidates: Vec<String>
+	},
+	/// A subterm evaluated to an infinite or `NaN` value (as in `Term::evaluate_finite`).
+	///
+	/// `term` is the offending subterm, as displayed, and `value` is its value.
+	NonFinite {
+		term: String,
+		value: f64
+	}
+}
+
+impl fmt::Display for EvalError {
+	/// Evaluation errors are displayed as human-readable messages.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::EvalError;
+	///
+	/// let e = EvalError::UndefinedVariable("x".to_string());
+	/// assert_eq!(&format!("{}", e), "No value provided for variable x");
+	/// let e = EvalError::DomainError { function: "sqrt".to_string(), value: -1.0 };
+	/// assert_eq!(&format!("{}", e), "Value -1 is outside the domain of sqrt.");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::EvalError::*;
+		match *self {
+			DivisionByZero => write!(f, "Attempted division by zero."),
+			UndefinedVariable(ref symbol) => write!(f, "No value provided for variable {}", symbol),
+			NoBindings(ref symbol) => write!(f, "No variable values provided (looking for {})", symbol),
+			DomainError { ref function, value } => write!(f, "Value {} is outside the domain of {}.", value, function),
+			NoMatchingBranch => write!(f, "No piecewise branch matched."),
+			NonReal(ref function) => write!(f, "Non-real value given to {}, which requires a real value.", function),
+			Irrational(ref function) => write!(f, "Cannot evaluate {} exactly.", function),
+			NoOperands(ref function) => write!(f, "No operands given to {}.", function),
+			AmbiguousVariable { ref symbol, ref candidates } => write!(f, "Ambiguous value for variable {} (could be any of {}).", symbol, candidates.join(", ")),
+			NonFinite { ref term, value } => write!(f, "Non-finite intermediate result {} from {}.", value, term)
+		}
+	}
+}
+
+impl Error for EvalError {}
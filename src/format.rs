@@ -0,0 +1,519 @@
+use condition::Condition;
+use std::fmt;
+use term::Term;
+
+/// Binding strengths used when deciding where parentheses are needed.
+const ADDITIVE: u8 = 1;
+const MULTIPLICATIVE: u8 = 2;
+const UNARY: u8 = 3;
+const EXPONENTIAL: u8 = 4;
+const ATOMIC: u8 = 5;
+
+impl Term {
+	/// The name under which a term is displayed (and parsed) as a function application, if it is one.
+	pub(crate) fn function_name(&self) -> Option<&'static str> {
+		use Term::*;
+		Some(match *self {
+			Sine(_) => "sin",
+			Cosine(_) => "cos",
+			Tangent(_) => "tan",
+			ArcSine(_) => "asin",
+			ArcCosine(_) => "acos",
+			ArcTangent(_) => "atan",
+			Exp(_) => "exp",
+			NaturalLog(_) => "ln",
+			Logarithm(_, _) => "log",
+			SquareRoot(_) => "sqrt",
+			NthRoot(_, _) => "root",
+			AbsoluteValue(_) => "abs",
+			Sinh(_) => "sinh",
+			Cosh(_) => "cosh",
+			Tanh(_) => "tanh",
+			Secant(_) => "sec",
+			Cosecant(_) => "csc",
+			Cotangent(_) => "cot",
+			Modulo(_, _) => "mod",
+			Factorial(_) => "factorial",
+			Summation { .. } => "sum",
+			IndexedProduct { .. } => "product",
+			Piecewise(_) => "piecewise",
+			Floor(_) => "floor",
+			Ceiling(_) => "ceil",
+			Sign(_) => "sign",
+			Maximum(_) => "max",
+			Minimum(_) => "min",
+			Round(_) => "round",
+			Truncate(_) => "trunc",
+			Fractional(_) => "fract",
+			Clamp { .. } => "clamp",
+			PowerInt(_, _) => "powi",
+			Derivative { .. } => "derivative",
+			Atan2 { .. } => "atan2",
+			Sigmoid(_) => "sigmoid",
+			ToRadians(_) => "radians",
+			ToDegrees(_) => "degrees",
+			Binomial { .. } => "binomial",
+			_ => return None
+		})
+	}
+
+	/// The binding strength of a term's outermost operation.
+	fn precedence(&self) -> u8 {
+		use Term::*;
+		match *self {
+			Sum(ref terms) | Difference(ref terms) | Product(ref terms) | Quotient(ref terms) if terms.len() == 1 => terms[0].precedence(),
+			Sum(ref terms) | Difference(ref terms) if terms.len() > 1 => ADDITIVE,
+			Product(ref terms) | Quotient(ref terms) if terms.len() > 1 => MULTIPLICATIVE,
+			Constant(value) if value < 0.0 => UNARY,
+			Negation(_) => UNARY,
+			Power(_, _) | PowerInt(_, _) => EXPONENTIAL,
+			_ => ATOMIC
+		}
+	}
+}
+
+/// Maps Greek letters to their LaTeX macros; other characters are used verbatim.
+fn latex_letter(symbol: char) -> String {
+	let name = match symbol {
+		'α' => "alpha", 'β' => "beta", 'γ' => "gamma", 'δ' => "delta", 'ε' => "epsilon", 'ζ' => "zeta",
+		'η' => "eta", 'θ' => "theta", 'ι' => "iota", 'κ' => "kappa", 'λ' => "lambda", 'μ' => "mu",
+		'ν' => "nu", 'ξ' => "xi", 'π' => "pi", 'ρ' => "rho", 'σ' => "sigma", 'τ' => "tau",
+		'υ' => "upsilon", 'φ' => "varphi", 'ϕ' => "phi", 'χ' => "chi", 'ψ' => "psi", 'ω' => "omega",
+		'Γ' => "Gamma", 'Δ' => "Delta", 'Θ' => "Theta", 'Λ' => "Lambda", 'Ξ' => "Xi", 'Π' => "Pi",
+		'Σ' => "Sigma", 'Υ' => "Upsilon", 'Φ' => "Phi", 'Ψ' => "Psi", 'Ω' => "Omega",
+		_ => return symbol.to_string()
+	};
+	format!("\\{}", name)
+}
+
+/// Renders a variable symbol as LaTeX.
+///
+/// Single letters are mapped with `latex_letter`, longer names are set upright, and anything following an underscore becomes a subscript.
+fn latex_symbol(symbol: &str) -> String {
+	let (name, subscript) = match symbol.find('_') {
+		Some(index) => (&symbol[..index], Some(&symbol[index + 1..])),
+		None => (symbol, None)
+	};
+	let mut chars = name.chars();
+	let mut rendered = match (chars.next(), chars.next()) {
+		(Some(letter), None) => latex_letter(letter),
+		_ => format!("\\mathrm{{{}}}", name)
+	};
+	if let Some(subscript) = subscript {
+		rendered.push_str(&format!("_{{{}}}", subscript));
+	}
+	rendered
+}
+
+/// Renders `term` as LaTeX, parenthesized if it binds less tightly than `minimum`.
+///
+/// Fractions group their contents visually, so they never need parentheses as operands.
+fn latex_operand(term: &Term, minimum: u8) -> String {
+	let precedence = match *term {
+		Term::Quotient(ref terms) if terms.len() > 1 => ATOMIC,
+		Term::Modulo(_, _) => MULTIPLICATIVE,
+		Term::Summation { .. } | Term::IndexedProduct { .. } => ADDITIVE,
+		_ => term.precedence()
+	};
+	if precedence < minimum {
+		format!("\\left({}\\right)", term.to_latex())
+	} else {
+		term.to_latex()
+	}
+}
+
+/// Renders `terms` as LaTeX, separated by `operator` (see `write_infix`).
+fn latex_infix(terms: &[Term], operator: &str, precedence: u8, associative: bool) -> String {
+	terms.iter().enumerate().map(|(index, term)| {
+		if index == 0 {
+			latex_operand(term, precedence)
+		} else {
+			format!(" {} {}", operator, latex_operand(term, if associative { precedence } else { precedence + 1 }))
+		}
+	}).collect()
+}
+
+/// Renders a named function applied to `term`, e.g. `\sin{x}`.
+fn latex_function(name: &str, term: &Term) -> String {
+	format!("\\{}{{{}}}", name, latex_operand(term, ATOMIC))
+}
+
+/// Renders a condition as LaTeX, e.g. `x \geq 0`.
+fn latex_condition(condition: &Condition) -> String {
+	let symbol = match *condition {
+		Condition::GreaterThan(_, _) => ">",
+		Condition::GreaterThanOrEqual(_, _) => "\\geq",
+		Condition::LessThan(_, _) => "<",
+		Condition::LessThanOrEqual(_, _) => "\\leq",
+		Condition::Equal(_, _) => "=",
+		Condition::NotEqual(_, _) => "\\neq"
+	};
+	let (a, b) = condition.operands();
+	format!("{} {} {}", a.to_latex(), symbol, b.to_latex())
+}
+
+/// A node of the tree rendered by `Term::pretty_tree`.
+enum TreeNode<'a> {
+	Term(&'a Term),
+	Condition(&'a Condition)
+}
+
+/// The label and children of a node of the tree rendered by `Term::pretty_tree`.
+fn tree_node(node: TreeNode) -> (String, Vec<TreeNode>) {
+	use Term::*;
+	let term = match node {
+		TreeNode::Term(term) => term,
+		TreeNode::Condition(condition) => {
+			let (a, b) = condition.operands();
+			return (condition.symbol().to_string(), vec!(TreeNode::Term(a), TreeNode::Term(b)));
+		}
+	};
+	let label = match *term {
+		Constant(value) => value.to_string(),
+		Variable(ref variable) => variable.symbol.clone(),
+		Pi => "π".to_string(),
+		E => "e".to_string(),
+		Sum(_) => "+".to_string(),
+		Difference(_) => "-".to_string(),
+		Product(_) => "*".to_string(),
+		Quotient(_) => "/".to_string(),
+		Power(_, _) => "^".to_string(),
+		PowerInt(_, exponent) => format!("^{}", exponent),
+		Negation(_) => "neg".to_string(),
+		Summation { ref index, .. } | IndexedProduct { ref index, .. } => format!("{} {}", term.function_name().unwrap_or_default(), index),
+		Derivative { ref variable, .. } => format!("{} {}", term.function_name().unwrap_or_default(), variable),
+		Piecewise(ref branches) => {
+			let children = branches.iter().flat_map(|(condition, term)| vec!(TreeNode::Condition(condition), TreeNode::Term(term))).collect();
+			return ("piecewise".to_string(), children);
+		},
+		_ => term.function_name().unwrap_or_default().to_string()
+	};
+	(label, term.children().into_iter().map(TreeNode::Term).collect())
+}
+
+/// Renders `node` and its descendants as lines of the tree rendered by `Term::pretty_tree`.
+///
+/// `prefix` is drawn before the node's own line and those of its descendants, `connector` joins the node to its parent, and `continuation` continues the parent's connectors past the node's descendants.
+fn write_tree(node: TreeNode, prefix: &str, connector: &str, continuation: &str, lines: &mut Vec<String>) {
+	let (label, children) = tree_node(node);
+	lines.push(format!("{}{}{}", prefix, connector, label));
+	let prefix = format!("{}{}", prefix, continuation);
+	let count = children.len();
+	for (index, child) in children.into_iter().enumerate() {
+		let last = index + 1 == count;
+		write_tree(child, &prefix, if last { "└── " } else { "├── " }, if last { "    " } else { "│   " }, lines);
+	}
+}
+
+/// Writes `term`, parenthesized if it binds less tightly than `minimum`.
+///
+/// Like the other writers below, this formats subterms with `f` itself, so that any precision given to it applies to their constants.
+fn write_operand(f: &mut fmt::Formatter, term: &Term, minimum: u8) -> fmt::Result {
+	if term.precedence() < minimum {
+		write!(f, "(")?;
+		fmt::Display::fmt(term, f)?;
+		write!(f, ")")
+	} else {
+		fmt::Display::fmt(term, f)
+	}
+}
+
+/// Writes `terms` as the comma-separated arguments of the function `name`.
+fn write_function(f: &mut fmt::Formatter, name: &str, terms: &[&Term]) -> fmt::Result {
+	write!(f, "{}(", name)?;
+	for (index, term) in terms.iter().enumerate() {
+		if index > 0 {
+			write!(f, ", ")?;
+		}
+		fmt::Display::fmt(*term, f)?;
+	}
+	write!(f, ")")
+}
+
+/// Writes `terms` separated by `operator`, parenthesizing all but the first operand if they bind no more tightly than the operator (for non-associative operators).
+fn write_infix(f: &mut fmt::Formatter, terms: &[Term], operator: &str, precedence: u8, associative: bool) -> fmt::Result {
+	for (index, term) in terms.iter().enumerate() {
+		if index == 0 {
+			write_operand(f, term, precedence)?;
+		} else {
+			write!(f, " {} ", operator)?;
+			write_operand(f, term, if associative { precedence } else { precedence + 1 })?;
+		}
+	}
+	Ok(())
+}
+
+impl Term {
+	/// Renders a term as LaTeX source.
+	///
+	/// Quotients are rendered as (nested) fractions, products use `\cdot`, and Greek-letter variables are mapped to their LaTeX macros. Empty differences and quotients, which have no infix form, are rendered as applications of `\operatorname{difference}` and `\operatorname{quotient}` to nothing.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let φ = Term::Variable(Variable::named('φ'));
+	/// let q = (&x + &φ) / Term::Constant(2.0);
+	/// assert_eq!(&q.to_latex(), "\\frac{x + \\varphi}{2}");
+	///
+	/// let s = Term::Sine(Box::new(x.clone() + Term::Constant(1.0)));
+	/// assert_eq!(&s.to_latex(), "\\sin{\\left(x + 1\\right)}");
+	///
+	/// let v = Term::Variable(Variable::named("v_0"));
+	/// let θ = Term::Variable(Variable::named("θ_max"));
+	/// assert_eq!(&(v * θ).to_latex(), "v_{0} \\cdot \\theta_{max}");
+	///
+	/// let p = Term::Power(Box::new(x.clone() * φ.clone()), Box::new(&x / &φ));
+	/// assert_eq!(&p.to_latex(), "\\left(x \\cdot \\varphi\\right)^{\\frac{x}{\\varphi}}");
+	///
+	/// let e = Term::Difference(vec!()) * Term::Quotient(vec!());
+	/// assert_eq!(&e.to_latex(), "\\operatorname{difference}\\left(\\right) \\cdot \\operatorname{quotient}\\left(\\right)");
+	/// ```
+	pub fn to_latex(&self) -> String {
+		use Term::*;
+		match *self {
+			Constant(value) => format!("{}", value),
+			Variable(ref variable) => latex_symbol(&variable.symbol),
+			Sum(ref terms) if terms.is_empty() => "0".to_string(),
+			Product(ref terms) if terms.is_empty() => "1".to_string(),
+			Difference(ref terms) if terms.is_empty() => "\\operatorname{difference}\\left(\\right)".to_string(),
+			Quotient(ref terms) if terms.is_empty() => "\\operatorname{quotient}\\left(\\right)".to_string(),
+			Sum(ref terms) => latex_infix(terms, "+", ADDITIVE, true),
+			Difference(ref terms) => latex_infix(terms, "-", ADDITIVE, false),
+			Product(ref terms) => latex_infix(terms, "\\cdot", MULTIPLICATIVE, true),
+			Quotient(ref terms) => {
+				let mut terms = terms.iter();
+				let first = terms.next().map(Term::to_latex).unwrap_or_default();
+				terms.fold(first, |numerator, denominator| format!("\\frac{{{}}}{{{}}}", numerator, denominator.to_latex()))
+			},
+			Sine(ref term) => latex_function("sin", term),
+			Cosine(ref term) => latex_function("cos", term),
+			Tangent(ref term) => latex_function("tan", term),
+			ArcSine(ref term) => latex_function("arcsin", term),
+			ArcCosine(ref term) => latex_function("arccos", term),
+			ArcTangent(ref term) => latex_function("arctan", term),
+			Power(ref base, ref exponent) => {
+				let base = match **base {
+					Constant(value) if value >= 0.0 => base.to_latex(),
+					Variable(_) | Pi | E => base.to_latex(),
+					_ => format!("\\left({}\\right)", base.to_latex())
+				};
+				format!("{}^{{{}}}", base, exponent.to_latex())
+			},
+			Exp(ref term) => format!("e^{{{}}}", term.to_latex()),
+			NaturalLog(ref term) => latex_function("ln", term),
+			Logarithm(ref term, ref base) => format!("\\log_{{{}}}{{{}}}", base.to_latex(), latex_operand(term, ATOMIC)),
+			SquareRoot(ref term) => format!("\\sqrt{{{}}}", term.to_latex()),
+			NthRoot(ref term, ref index) => format!("\\sqrt[{}]{{{}}}", index.to_latex(), term.to_latex()),
+			AbsoluteValue(ref term) => format!("\\left|{}\\right|", term.to_latex()),
+			Negation(ref term) => format!("-{}", latex_operand(term, EXPONENTIAL)),
+			Sinh(ref term) => latex_function("sinh", term),
+			Cosh(ref term) => latex_function("cosh", term),
+			Tanh(ref term) => latex_function("tanh", term),
+			Secant(ref term) => latex_function("sec", term),
+			Cosecant(ref term) => latex_function("csc", term),
+			Cotangent(ref term) => latex_function("cot", term),
+			Modulo(ref dividend, ref divisor) => format!("{} \\bmod {}", latex_operand(dividend, MULTIPLICATIVE), latex_operand(divisor, MULTIPLICATIVE + 1)),
+			Factorial(ref term) => format!("{}!", latex_operand(term, ATOMIC)),
+			Summation { ref index, ref from, ref to, ref body } => format!("\\sum_{{{} = {}}}^{{{}}} {}", latex_symbol(index), from.to_latex(), to.to_latex(), latex_operand(body, MULTIPLICATIVE)),
+			IndexedProduct { ref index, ref from, ref to, ref body } => format!("\\prod_{{{} = {}}}^{{{}}} {}", latex_symbol(index), from.to_latex(), to.to_latex(), latex_operand(body, MULTIPLICATIVE)),
+			Pi => "\\pi".to_string(),
+			E => "e".to_string(),
+			Piecewise(ref branches) => {
+				let cases = branches.iter().map(|(condition, term)| format!("{} & {}", term.to_latex(), latex_condition(condition))).collect::<Vec<_>>();
+				format!("\\begin{{cases}} {} \\end{{cases}}", cases.join(" \\\\ "))
+			},
+			Floor(ref term) => format!("\\left\\lfloor {} \\right\\rfloor", term.to_latex()),
+			Ceiling(ref term) => format!("\\left\\lceil {} \\right\\rceil", term.to_latex()),
+			Sign(ref term) => format!("\\operatorname{{sgn}}{{{}}}", latex_operand(term, ATOMIC)),
+			Maximum(ref terms) | Minimum(ref terms) => {
+				let terms = terms.iter().map(Term::to_latex).collect::<Vec<_>>();
+				format!("\\{}\\left({}\\right)", self.function_name().unwrap_or_default(), terms.join(", "))
+			},
+			Round(ref term) => format!("\\left\\lfloor {} \\right\\rceil", term.to_latex()),
+			Truncate(ref term) => format!("\\operatorname{{trunc}}{{{}}}", latex_operand(term, ATOMIC)),
+			Fractional(ref term) => format!("\\operatorname{{frac}}{{{}}}", latex_operand(term, ATOMIC)),
+			Clamp { ref value, ref min, ref max } => format!("\\operatorname{{clamp}}\\left({}, {}, {}\\right)", value.to_latex(), min.to_latex(), max.to_latex()),
+			PowerInt(ref base, exponent) => Power(base.clone(), Box::new(Constant(f64::from(exponent)))).to_latex(),
+			Derivative { ref body, ref variable } => format!("\\frac{{d}}{{d{}}}\\left({}\\right)", latex_symbol(variable), body.to_latex()),
+			Atan2 { ref y, ref x } => format!("\\operatorname{{atan2}}\\left({}, {}\\right)", y.to_latex(), x.to_latex()),
+			Sigmoid(ref term) => format!("\\sigma\\left({}\\right)", term.to_latex()),
+			ToRadians(ref term) => format!("\\operatorname{{rad}}\\left({}\\right)", term.to_latex()),
+			ToDegrees(ref term) => format!("\\operatorname{{deg}}\\left({}\\right)", term.to_latex()),
+			Binomial { ref n, ref k } => format!("\\binom{{{}}}{{{}}}", n.to_latex(), k.to_latex())
+		}
+	}
+	/// Displays a term (as with its `Display` implementation), with every constant written to `decimals` decimal places.
+	///
+	/// This is equivalent to formatting the term with a precision, as in `format!("{:.3}", term)`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t = Term::Constant(1.0 / 3.0) + Term::from('x');
+	/// assert_eq!(&t.display_with_precision(3), "0.333 + x");
+	///
+	/// let t: Term = "sin(0.1 + 0.2) * 2".parse().unwrap();
+	/// assert_eq!(&t.display_with_precision(1), "sin(0.1 + 0.2) * 2.0");
+	/// assert_eq!(&format!("{:.2}", t), "sin(0.10 + 0.20) * 2.00");
+	/// ```
+	pub fn display_with_precision(&self, decimals: usize) -> String {
+		format!("{:.*}", decimals, self)
+	}
+	/// Renders a term as a tree, with each subterm on its own line beneath the term containing it.
+	///
+	/// Each compound term is labelled with its operator (as in `Term::to_sexp`) or function name, and its operands are listed beneath it, connected by `├──` and `└──`. Constants, variables, and the constants π and e are labelled by their values and symbols. Summations and indexed products are labelled with their indices, derivatives with the variables they are taken with respect to, and the branches of piecewise terms are listed as alternating conditions (labelled with their comparisons) and terms.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x + 2 * sin(y)".parse().unwrap();
+	/// assert_eq!(t.pretty_tree(), "\
+	/// +
+	/// ├── x
+	/// └── *
+	///     ├── 2
+	///     └── sin
+	///         └── y");
+	///
+	/// let t: Term = "piecewise(x < 0, -x, x >= 0, x)".parse().unwrap();
+	/// assert_eq!(t.pretty_tree(), "\
+	/// piecewise
+	/// ├── <
+	/// │   ├── x
+	/// │   └── 0
+	/// ├── neg
+	/// │   └── x
+	/// ├── >=
+	/// │   ├── x
+	/// │   └── 0
+	/// └── x");
+	/// ```
+	pub fn pretty_tree(&self) -> String {
+		let mut lines = vec!();
+		write_tree(TreeNode::Term(self), "", "", "", &mut lines);
+		lines.join("\n")
+	}
+}
+
+impl fmt::Display for Term {
+	/// Terms are displayed in infix notation, with parentheses inserted only where precedence requires them.
+	///
+	/// A precision (as in `{:.3}`) is applied to every constant in the term (see `Term::display_with_precision`).
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let y = Term::Variable(Variable::named('y'));
+	/// let s = (&x + &y) * Term::Constant(2.0);
+	/// assert_eq!(&format!("{}", s), "(x + y) * 2");
+	///
+	/// let t = x.clone() + y.clone() * Term::Constant(2.0);
+	/// assert_eq!(&format!("{}", t), "x + y * 2");
+	///
+	/// let u = Term::Sine(Box::new(x.clone())) - (y - Term::Constant(1.5));
+	/// assert_eq!(&format!("{}", u), "sin(x) - (y - 1.5)");
+	///
+	/// let v = Term::Power(Box::new(-x), Box::new(Term::Constant(2.0)));
+	/// assert_eq!(&format!("{}", v), "(-x)^2");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use Term::*;
+		match *self {
+			Constant(value) => match f.precision() {
+				Some(precision) => write!(f, "{:.*}", precision, value),
+				None => write!(f, "{}", value)
+			},
+			Variable(ref variable) => write!(f, "{:?}", variable),
+			Sum(ref terms) if terms.is_empty() => write!(f, "0"),
+			Product(ref terms) if terms.is_empty() => write!(f, "1"),
+			Sum(ref terms) => write_infix(f, terms, "+", ADDITIVE, true),
+			Difference(ref terms) => write_infix(f, terms, "-", ADDITIVE, false),
+			Product(ref terms) => write_infix(f, terms, "*", MULTIPLICATIVE, true),
+			Quotient(ref terms) => write_infix(f, terms, "/", MULTIPLICATIVE, false),
+			Sine(ref term) => write_function(f, "sin", &[term]),
+			Cosine(ref term) => write_function(f, "cos", &[term]),
+			Tangent(ref term) => write_function(f, "tan", &[term]),
+			ArcSine(ref term) => write_function(f, "asin", &[term]),
+			ArcCosine(ref term) => write_function(f, "acos", &[term]),
+			ArcTangent(ref term) => write_function(f, "atan", &[term]),
+			Power(ref base, ref exponent) => {
+				write_operand(f, base, EXPONENTIAL + 1)?;
+				write!(f, "^")?;
+				write_operand(f, exponent, EXPONENTIAL)
+			},
+			Exp(ref term) => write_function(f, "exp", &[term]),
+			NaturalLog(ref term) => write_function(f, "ln", &[term]),
+			Logarithm(ref term, ref base) => write_function(f, "log", &[term, base]),
+			SquareRoot(ref term) => write_function(f, "sqrt", &[term]),
+			NthRoot(ref term, ref index) => write_function(f, "root", &[term, index]),
+			AbsoluteValue(ref term) => write_function(f, "abs", &[term]),
+			Negation(ref term) => {
+				write!(f, "-")?;
+				write_operand(f, term, EXPONENTIAL)
+			},
+			Sinh(ref term) => write_function(f, "sinh", &[term]),
+			Cosh(ref term) => write_function(f, "cosh", &[term]),
+			Tanh(ref term) => write_function(f, "tanh", &[term]),
+			Secant(ref term) => write_function(f, "sec", &[term]),
+			Cosecant(ref term) => write_function(f, "csc", &[term]),
+			Cotangent(ref term) => write_function(f, "cot", &[term]),
+			Modulo(ref dividend, ref divisor) => write_function(f, "mod", &[dividend, divisor]),
+			Factorial(ref term) => write_function(f, "factorial", &[term]),
+			Summation { ref index, ref from, ref to, ref body } | IndexedProduct { ref index, ref from, ref to, ref body } => {
+				write!(f, "{}(", self.function_name().unwrap_or_default())?;
+				fmt::Display::fmt(&**body, f)?;
+				write!(f, ", {}, ", index)?;
+				fmt::Display::fmt(&**from, f)?;
+				write!(f, ", ")?;
+				fmt::Display::fmt(&**to, f)?;
+				write!(f, ")")
+			},
+			Pi => write!(f, "π"),
+			E => write!(f, "e"),
+			Piecewise(ref branches) => {
+				write!(f, "piecewise(")?;
+				for (index, (condition, term)) in branches.iter().enumerate() {
+					if index > 0 {
+						write!(f, ", ")?;
+					}
+					fmt::Display::fmt(condition, f)?;
+					write!(f, ", ")?;
+					fmt::Display::fmt(term, f)?;
+				}
+				write!(f, ")")
+			},
+			Floor(ref term) => write_function(f, "floor", &[term]),
+			Ceiling(ref term) => write_function(f, "ceil", &[term]),
+			Sign(ref term) => write_function(f, "sign", &[term]),
+			Maximum(ref terms) | Minimum(ref terms) => write_function(f, self.function_name().unwrap_or_default(), &terms.iter().collect::<Vec<_>>()),
+			Round(ref term) => write_function(f, "round", &[term]),
+			Truncate(ref term) => write_function(f, "trunc", &[term]),
+			Fractional(ref term) => write_function(f, "fract", &[term]),
+			Clamp { ref value, ref min, ref max } => write_function(f, "clamp", &[value, min, max]),
+			PowerInt(ref base, exponent) => {
+				write_operand(f, base, EXPONENTIAL + 1)?;
+				if exponent < 0 {
+					write!(f, "^({})", exponent)
+				} else {
+					write!(f, "^{}", exponent)
+				}
+			},
+			Derivative { ref body, ref variable } => {
+				write!(f, "derivative(")?;
+				fmt::Display::fmt(&**body, f)?;
+				write!(f, ", {})", variable)
+			},
+			Atan2 { ref y, ref x } => write_function(f, "atan2", &[y, x]),
+			Sigmoid(ref term) => write_function(f, "sigmoid", &[term]),
+			ToRadians(ref term) => write_function(f, "radians", &[term]),
+			ToDegrees(ref term) => write_function(f, "degrees", &[term]),
+			Binomial { ref n, ref k } => write_function(f, "binomial", &[n, k])
+		}
+	}
+}
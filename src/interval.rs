@@ -0,0 +1,311 @@
+use condition::Condition;
+use error::EvalError;
+use std::collections::HashMap;
+use std::f64::consts;
+use term::{AngleMode, Term, DIVISION_EPSILON, POLE_EPSILON};
+
+/// A closed interval of real numbers, as its lower and upper bounds.
+type Interval = (f64, f64);
+
+type IntervalValues = HashMap<String, Interval>;
+
+/// The interval containing exactly `value`.
+fn point(value: f64) -> Interval {
+	(value, value)
+}
+
+/// The smallest interval containing each of the given values.
+fn hull<I: IntoIterator<Item = f64>>(values: I) -> Interval {
+	values.into_iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lower, upper), value| (lower.min(value), upper.max(value)))
+}
+
+fn multiply(a: Interval, b: Interval) -> Interval {
+	hull(vec!(a.0 * b.0, a.0 * b.1, a.1 * b.0, a.1 * b.1))
+}
+
+/// The range of the absolute value over an interval.
+fn magnitude(interval: Interval) -> Interval {
+	match interval {
+		(a, b) if a >= 0.0 => (a, b),
+		(a, b) if b <= 0.0 => (-b, -a),
+		(a, b) => (0.0, b.max(-a))
+	}
+}
+
+/// The reciprocal of an interval, which must not contain (approximately) zero.
+fn reciprocal(interval: Interval, epsilon: f64) -> Result<Interval, EvalError> {
+	if interval.0 < epsilon && interval.1 > -epsilon {
+		return Err(EvalError::DivisionByZero);
+	}
+	Ok((1.0 / interval.1, 1.0 / interval.0))
+}
+
+/// Whether the interval contains any of the points `offset + k * period` for integers `k`.
+fn contains_periodic(interval: Interval, offset: f64, period: f64) -> bool {
+	((interval.0 - offset) / period).ceil() <= ((interval.1 - offset) / period).floor()
+}
+
+/// The range of the sine over an interval (of radians).
+fn sine(interval: Interval) -> Interval {
+	let period = 2.0 * consts::PI;
+	if interval.1 - interval.0 >= period {
+		return (-1.0, 1.0);
+	}
+	let (lower, upper) = hull(vec!(interval.0.sin(), interval.1.sin()));
+	(
+		if contains_periodic(interval, -consts::FRAC_PI_2, period) { -1.0 } else { lower },
+		if contains_periodic(interval, consts::FRAC_PI_2, period) { 1.0 } else { upper }
+	)
+}
+
+/// The range of the cosine over an interval (of radians).
+fn cosine(interval: Interval) -> Interval {
+	sine((interval.0 + consts::FRAC_PI_2, interval.1 + consts::FRAC_PI_2))
+}
+
+/// The range of an integer power of an interval.
+fn integer_power(base: Interval, exponent: i32) -> Result<Interval, EvalError> {
+	if exponent < 0 {
+		return reciprocal(integer_power(base, -exponent)?, DIVISION_EPSILON);
+	}
+	let (lower, upper) = hull(vec!(base.0.powi(exponent), base.1.powi(exponent)));
+	if exponent % 2 == 0 && base.0 < 0.0 && base.1 > 0.0 {
+		Ok((0.0, upper))
+	} else {
+		Ok((lower, upper))
+	}
+}
+
+/// Whether a condition holds for some values (`possibly`) or for all values (`!possibly`) of its operands in the given intervals.
+fn holds(condition: &Condition, a: Interval, b: Interval, possibly: bool) -> bool {
+	let overlapping = a.0 <= b.1 && b.0 <= a.1;
+	match (condition, possibly) {
+		(Condition::Equal(_, _), true) => overlapping,
+		(Condition::NotEqual(_, _), false) => !overlapping,
+		_ => {
+			// Otherwise, the extremes are attained at the corners.
+			let corners = [(a.0, b.0), (a.0, b.1), (a.1, b.0), (a.1, b.1)];
+			let mut corners = corners.iter().map(|&(a, b)| condition.compare(a, b));
+			if possibly { corners.any(|holds| holds) } else { corners.all(|holds| holds) }
+		}
+	}
+}
+
+impl Term {
+	/// Evaluates a term using interval arithmetic, given intervals containing the values of its variables, producing an interval containing every value the term can take.
+	///
+	/// Intervals are given and produced as pairs of their (inclusive) lower and upper bounds. Each operation is carried out on intervals, so the result is guaranteed to contain the term's value for any choice of variable values within their intervals, but may be wider than necessary; in particular, each occurrence of a variable is treated independently, so `x - x` is not known to be zero. Angles are measured in radians.
+	///
+	/// Errors are produced whenever they could arise for some choice of values, so dividing by an interval containing zero produces `EvalError::DivisionByZero`, and taking the square root of an interval containing negative numbers produces `EvalError::DomainError`. For a similar reason, non-integer powers of intervals containing negative numbers, roots with varying indices of intervals containing negative numbers, and factorials of intervals extending below one (unless they are single points) are rejected, as are clamps with unordered or `NaN` bounds. Summation and product bounds must be single integers, and piecewise terms produce the smallest interval containing the values of every branch which could be selected.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), (-2.0, 3.0));
+	/// let t: Term = "x * x".parse().unwrap();
+	/// assert_eq!(t.evaluate_interval(&values), Ok((-6.0, 9.0)));
+	/// let t: Term = "x^2 + 1".parse().unwrap();
+	/// assert_eq!(t.evaluate_interval(&values), Ok((1.0, 10.0)));
+	///
+	/// let t: Term = "sin(x)".parse().unwrap();
+	/// let (lower, upper) = t.evaluate_interval(&values).unwrap();
+	/// assert!((lower + 1.0).abs() < 0.00001 && (upper - 1.0).abs() < 0.00001);
+	///
+	/// let t: Term = "1 / x".parse().unwrap();
+	/// assert_eq!(t.evaluate_interval(&values), Err(EvalError::DivisionByZero));
+	///
+	/// // Clamping to bounds which are not numbers (here, `inf - inf`) is an error.
+	/// values.insert("x".to_string(), (0.0, std::f64::INFINITY));
+	/// values.insert("y".to_string(), (std::f64::NEG_INFINITY, std::f64::NEG_INFINITY));
+	/// values.insert("z".to_string(), (1.0, 2.0));
+	/// let t: Term = "clamp(z, x + y, 5)".parse().unwrap();
+	/// match t.evaluate_interval(&values) {
+	///     Err(EvalError::DomainError { ref function, value }) => assert!(function == "clamp bounds" && value.is_nan()),
+	///     result => panic!("unexpected result {:?}", result)
+	/// }
+	/// ```
+	pub fn evaluate_interval(&self, values: &IntervalValues) -> Result<Interval, EvalError> {
+		self.evaluate_postorder(|term, operands| term.apply_interval(&operands, values))
+	}
+
+	/// Computes the interval containing the values of a term given intervals containing the values of its children, in order (see `Term::apply`).
+	fn apply_interval(&self, operands: &[Interval], values: &IntervalValues) -> Result<Interval, EvalError> {
+		use Term::*;
+		// The image of the operand under `self`, which is monotonic (and defined) on it.
+		let monotonic = |interval: Interval| -> Result<Interval, EvalError> {
+			let a = self.apply(&[interval.0], None, AngleMode::Radians)?;
+			let b = self.apply(&[interval.1], None, AngleMode::Radians)?;
+			Ok(hull(vec!(a, b)))
+		};
+		match *self {
+			Constant(value) => Ok(point(value)),
+			Variable(ref variable) => match values.get(&variable.symbol) {
+				Some(&(a, b)) => Ok(hull(vec!(a, b))),
+				None => Err(EvalError::UndefinedVariable(variable.symbol.clone()))
+			},
+			Pi => Ok(point(consts::PI)),
+			E => Ok(point(consts::E)),
+			Sum(_) => Ok(operands.iter().fold(point(0.0), |sum, &(a, b)| (sum.0 + a, sum.1 + b))),
+			Difference(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("difference".to_string()))?;
+				Ok(rest.iter().fold(*first, |difference, &(a, b)| (difference.0 - b, difference.1 - a)))
+			},
+			Product(_) => Ok(operands.iter().fold(point(1.0), |product, &interval| multiply(product, interval))),
+			Quotient(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("quotient".to_string()))?;
+				rest.iter().try_fold(*first, |quotient, &interval| Ok(multiply(quotient, reciprocal(interval, DIVISION_EPSILON)?)))
+			},
+			Negation(_) => Ok((-operands[0].1, -operands[0].0)),
+			AbsoluteValue(_) => Ok(magnitude(operands[0])),
+			Sine(_) => Ok(sine(operands[0])),
+			Cosine(_) => Ok(cosine(operands[0])),
+			Tangent(_) | Cotangent(_) => {
+				// Both are monotonic between consecutive poles.
+				let poles = if matches!(*self, Tangent(_)) { consts::FRAC_PI_2 } else { 0.0 };
+				if operands[0].1 - operands[0].0 >= consts::PI || contains_periodic(operands[0], poles, consts::PI) {
+					return Err(EvalError::DivisionByZero);
+				}
+				monotonic(operands[0])
+			},
+			Secant(_) => reciprocal(cosine(operands[0]), POLE_EPSILON),
+			Cosecant(_) => reciprocal(sine(operands[0]), POLE_EPSILON),
+			Exp(_) | NaturalLog(_) | SquareRoot(_) | ArcSine(_) | ArcCosine(_) | ArcTangent(_) | Sinh(_) | Tanh(_) | Sigmoid(_) | ToRadians(_) | ToDegrees(_) |
+			Floor(_) | Ceiling(_) | Sign(_) | Round(_) | Truncate(_) => monotonic(operands[0]),
+			Fractional(_) => {
+				// The fractional part is increasing between consecutive integers (and across zero).
+				let (a, b) = operands[0];
+				if a.trunc() == b.trunc() {
+					return monotonic((a, b));
+				}
+				Ok((if a < 0.0 { -1.0 } else { 0.0 }, if b > 0.0 { 1.0 } else { 0.0 }))
+			},
+			Cosh(_) => {
+				// The hyperbolic cosine is even, and increasing on the non-negative numbers.
+				monotonic(magnitude(operands[0]))
+			},
+			Logarithm(_, _) => {
+				let logarithm = |interval: Interval| NaturalLog(Box::new(Constant(0.0))).apply_interval(&[interval], values);
+				Ok(multiply(logarithm(operands[0])?, reciprocal(logarithm(operands[1])?, DIVISION_EPSILON)?))
+			},
+			Power(_, _) => {
+				let (base, exponent) = (operands[0], operands[1]);
+				if exponent.0 == exponent.1 && exponent.0.fract() == 0.0 && exponent.0.abs() <= f64::from(i32::MAX) {
+					return integer_power(base, exponent.0 as i32);
+				}
+				if base.0 < 0.0 || (base.0 == 0.0 && exponent.0 <= 0.0) {
+					return Err(EvalError::DomainError { function: "^".to_string(), value: base.0 });
+				}
+				// Powers of non-negative bases are monotonic in both the base and the exponent.
+				Ok(hull(vec!(base.0.powf(exponent.0), base.0.powf(exponent.1), base.1.powf(exponent.0), base.1.powf(exponent.1))))
+			},
+			PowerInt(_, exponent) => integer_power(operands[0], exponent),
+			NthRoot(_, _) => {
+				let (radicand, index) = (operands[0], operands[1]);
+				if radicand.0 < 0.0 && index.0 != index.1 {
+					return Err(EvalError::DomainError { function: "root".to_string(), value: radicand.0 });
+				}
+				// Roots are monotonic in both the radicand and the index (where defined).
+				let corners = [(radicand.0, index.0), (radicand.0, index.1), (radicand.1, index.0), (radicand.1, index.1)];
+				let corners = corners.iter().map(|&(radicand, index)| self.apply(&[radicand, index], None, AngleMode::Radians)).collect::<Result<Vec<_>, _>>()?;
+				Ok(hull(corners))
+			},
+			Modulo(_, _) => {
+				let divisor = operands[1];
+				if divisor.0 < DIVISION_EPSILON && divisor.1 > -DIVISION_EPSILON {
+					return Err(EvalError::DivisionByZero);
+				}
+				if operands[0].0 == operands[0].1 && divisor.0 == divisor.1 {
+					return Ok(point(self.apply(&[operands[0].0, divisor.0], None, AngleMode::Radians)?));
+				}
+				Ok((0.0, divisor.0.abs().max(divisor.1.abs())))
+			},
+			Factorial(_) => {
+				// The factorial is increasing from its minimum (just below one half) on.
+				let (a, b) = operands[0];
+				if a < 1.0 && a != b {
+					return Err(EvalError::DomainError { function: "factorial".to_string(), value: a });
+				}
+				monotonic((a, b))
+			},
+			Binomial { .. } => {
+				// The binomial coefficient is only defined for integers, so its operands must be known exactly.
+				let (n, k) = (operands[0], operands[1]);
+				if n.0 != n.1 {
+					return Err(EvalError::DomainError { function: "binomial".to_string(), value: n.0 });
+				}
+				if k.0 != k.1 {
+					return Err(EvalError::DomainError { function: "binomial".to_string(), value: k.0 });
+				}
+				Ok(point(self.apply(&[n.0, k.0], None, AngleMode::Radians)?))
+			},
+			Maximum(_) | Minimum(_) => {
+				let lower = self.apply(&operands.iter().map(|interval| interval.0).collect::<Vec<_>>(), None, AngleMode::Radians)?;
+				let upper = self.apply(&operands.iter().map(|interval| interval.1).collect::<Vec<_>>(), None, AngleMode::Radians)?;
+				Ok((lower, upper))
+			},
+			Clamp { .. } => {
+				// Clamping is non-decreasing in the value and in both bounds, which must be ordered.
+				let (value, min, max) = (operands[0], operands[1], operands[2]);
+				if let Some(&bound) = [min.0, min.1, max.0, max.1].iter().find(|bound| bound.is_nan()) {
+					return Err(EvalError::DomainError { function: "clamp bounds".to_string(), value: bound });
+				}
+				if min.1 > max.0 {
+					return Err(EvalError::DomainError { function: "clamp bounds".to_string(), value: min.1 });
+				}
+				Ok((value.0.clamp(min.0, max.0), value.1.clamp(min.1, max.1)))
+			},
+			Atan2 { .. } => {
+				let (y, x) = (operands[0], operands[1]);
+				// The angle jumps from π to -π across the negative x-axis, and takes every value around the origin.
+				if x.0 <= 0.0 && y.0 <= 0.0 && y.1 >= 0.0 {
+					return Ok((-consts::PI, consts::PI));
+				}
+				// Elsewhere, the extreme angles are those of the corners.
+				Ok(hull(vec!(y.0.atan2(x.0), y.0.atan2(x.1), y.1.atan2(x.0), y.1.atan2(x.1))))
+			},
+			Summation { ref index, ref from, ref to, ref body } | IndexedProduct { ref index, ref from, ref to, ref body } => {
+				let summation = matches!(*self, Summation { .. });
+				let function = if summation { "summation" } else { "product" };
+				let bound = |term: &Term| -> Result<f64, EvalError> {
+					match term.evaluate_interval(values)? {
+						(a, b) if a == b && a.fract() == 0.0 => Ok(a),
+						(a, _) => Err(EvalError::DomainError { function: format!("{} bound", function), value: a })
+					}
+				};
+				let (from, to) = (bound(from)?, bound(to)?);
+				let mut bindings = values.clone();
+				let mut result = if summation { point(0.0) } else { point(1.0) };
+				let mut value = from;
+				while value <= to {
+					bindings.insert(index.clone(), point(value));
+					let term = body.evaluate_interval(&bindings)?;
+					result = if summation { (result.0 + term.0, result.1 + term.1) } else { multiply(result, term) };
+					value += 1.0;
+				}
+				Ok(result)
+			},
+			Derivative { ref body, ref variable } => body.derivative(variable).evaluate_interval(values),
+			Piecewise(ref branches) => {
+				let mut range: Option<Interval> = None;
+				for (condition, term) in branches {
+					let (a, b) = condition.operands();
+					let (a, b) = (a.evaluate_interval(values)?, b.evaluate_interval(values)?);
+					if holds(condition, a, b, true) {
+						let value = term.evaluate_interval(values)?;
+						range = Some(match range {
+							Some(range) => hull(vec!(range.0, range.1, value.0, value.1)),
+							None => value
+						});
+					}
+					if holds(condition, a, b, false) {
+						break;
+					}
+				}
+				range.ok_or(EvalError::NoMatchingBranch)
+			}
+		}
+	}
+}
@@ -1,8 +1,44 @@
+#[cfg(feature = "serde")]
+#[macro_use]
+extern crate serde;
+#[cfg(feature = "complex")]
+extern crate num_complex;
+#[cfg(feature = "rational")]
+extern crate num_rational;
+#[cfg(feature = "rational")]
+extern crate num_traits;
+
+mod builder;
+mod calculus;
+mod compile;
+#[cfg(feature = "complex")]
+mod complex;
+mod condition;
+mod error;
+mod format;
+mod interval;
+mod numeric;
+mod parse;
+mod polynomial;
+#[cfg(feature = "rational")]
+mod rational;
+#[cfg(feature = "f32")]
+mod single;
+mod simplify;
 mod term;
+mod units;
 mod variable;
 
-pub use term::Term;
-pub use variable::Variable;
+pub use builder::TermBuilder;
+pub use condition::Condition;
+pub use error::EvalError;
+#[cfg(feature = "complex")]
+pub use num_complex::Complex64;
+#[cfg(feature = "rational")]
+pub use num_rational::BigRational;
+pub use term::{AngleMode, Term};
+pub use units::{Dimension, Quantity};
+pub use variable::{Assumption, Variable};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use term::{DIVISION_EPSILON, Term};
+
+impl Term {
+	/// Numerically finds a root of a term, treated as a function of the variable `symbol`, using Newton's method.
+	///
+	/// Starting from `initial_guess`, the estimate is repeatedly refined using the term's (symbolic) derivative until the term evaluates to within `tolerance` of zero, at which point the estimate is returned. An error is returned if evaluation fails (for example, because the term involves other variables), if the derivative vanishes at some estimate, or if no root has been found after `max_iterations` refinements.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x^2 - 4".parse().unwrap();
+	/// let root = t.find_root("x", 3.0, 0.000001, 100).unwrap();
+	/// assert!((root - 2.0).abs() < 0.00001);
+	///
+	/// // The derivative vanishes at the initial guess.
+	/// assert!(t.find_root("x", 0.0, 0.000001, 100).is_err());
+	/// // x^2 + 1 has no real roots.
+	/// let t: Term = "x^2 + 1".parse().unwrap();
+	/// assert!(t.find_root("x", 3.0, 0.000001, 100).is_err());
+	/// ```
+	pub fn find_root(&self, symbol: &str, initial_guess: f64, tolerance: f64, max_iterations: usize) -> Result<f64, String> {
+		let derivative = self.derivative(symbol);
+		let mut estimate = initial_guess;
+		for _ in 0..max_iterations {
+			let value = self.evaluate_at(symbol, estimate)?;
+			if value.abs() < tolerance {
+				return Ok(estimate);
+			}
+			let slope = derivative.evaluate_at(symbol, estimate)?;
+			if slope.abs() < DIVISION_EPSILON {
+				return Err(format!("The derivative vanished at {} = {} before a root was found.", symbol, estimate));
+			}
+			estimate -= value / slope;
+			if !estimate.is_finite() {
+				return Err(format!("Newton's method diverged while searching for a root in {}.", symbol));
+			}
+		}
+		if self.evaluate_at(symbol, estimate)?.abs() < tolerance {
+			Ok(estimate)
+		} else {
+			Err(format!("Newton's method did not converge within {} iterations (last estimate {} = {}).", max_iterations, symbol, estimate))
+		}
+	}
+
+	/// Numerically integrates a term, treated as a function of the variable `symbol`, from `lower` to `upper` using Simpson's rule.
+	///
+	/// The interval is divided into `steps` subintervals, which must be a positive even number. An error is returned if it is not, or if evaluating the term fails at any point in the interval.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x^2".parse().unwrap();
+	/// assert!((t.definite_integral("x", 0.0, 1.0, 10).unwrap() - 1.0 / 3.0).abs() < 0.00001);
+	/// let t: Term = "sin(x)".parse().unwrap();
+	/// assert!((t.definite_integral("x", 0.0, 3.14159265358979, 100).unwrap() - 2.0).abs() < 0.00001);
+	///
+	/// assert!(t.definite_integral("x", 0.0, 1.0, 7).is_err());
+	/// let t: Term = "1 / x".parse().unwrap();
+	/// assert!(t.definite_integral("x", 0.0, 1.0, 10).is_err());
+	/// ```
+	pub fn definite_integral(&self, symbol: &str, lower: f64, upper: f64, steps: usize) -> Result<f64, String> {
+		if steps == 0 || !steps.is_multiple_of(2) {
+			return Err(format!("Simpson's rule requires a positive even number of steps ({} given).", steps));
+		}
+		let width = (upper - lower) / steps as f64;
+		let mut sum = self.evaluate_at(symbol, lower)? + self.evaluate_at(symbol, upper)?;
+		for step in 1..steps {
+			let weight = if step.is_multiple_of(2) { 2.0 } else { 4.0 };
+			sum += weight * self.evaluate_at(symbol, lower + step as f64 * width)?;
+		}
+		Ok(sum * width / 3.0)
+	}
+
+	/// Evaluates a term with only the variable `symbol` bound (to `value`), reporting errors as strings.
+	pub(crate) fn evaluate_at(&self, symbol: &str, value: f64) -> Result<f64, String> {
+		let mut values = HashMap::new();
+		values.insert(symbol.to_string(), value);
+		self.evaluate(&values).map_err(|error| error.to_string())
+	}
+}
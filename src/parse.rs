@@ -0,0 +1,763 @@
+use condition::Condition;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use term::Term;
+use variable::Variable;
+
+/// The lexical units of an infix expression.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+	Number(f64),
+	Identifier(String),
+	Operator(char),
+	Comparison(&'static str),
+	LeftParenthesis,
+	RightParenthesis,
+	Comma
+}
+
+impl fmt::Display for Token {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Token::Number(value) => write!(f, "{}", value),
+			Token::Identifier(ref name) => write!(f, "{}", name),
+			Token::Operator(operator) => write!(f, "{}", operator),
+			Token::Comparison(comparison) => write!(f, "{}", comparison),
+			Token::LeftParenthesis => write!(f, "("),
+			Token::RightParenthesis => write!(f, ")"),
+			Token::Comma => write!(f, ",")
+		}
+	}
+}
+
+/// Splits `s` into tokens, each paired with the (character) position at which it starts.
+fn tokenize(s: &str) -> Result<Vec<(Token, usize)>, String> {
+	let chars = s.chars().collect::<Vec<_>>();
+	let mut tokens = vec!();
+	let mut index = 0;
+	while index < chars.len() {
+		let c = chars[index];
+		let start = index;
+		if c.is_whitespace() {
+			index += 1;
+			continue;
+		}
+		let token = match c {
+			'+' | '-' | '*' | '/' | '^' => Token::Operator(c),
+			'(' => Token::LeftParenthesis,
+			')' => Token::RightParenthesis,
+			',' => Token::Comma,
+			'<' | '>' | '=' | '!' => {
+				let equals = chars.get(index + 1) == Some(&'=');
+				if equals {
+					index += 1;
+				}
+				Token::Comparison(match (c, equals) {
+					('<', false) => "<",
+					('<', true) => "<=",
+					('>', false) => ">",
+					('>', true) => ">=",
+					('=', true) => "==",
+					('!', true) => "!=",
+					_ => return Err(format!("Unexpected character '{}' at position {}.", c, start))
+				})
+			},
+			_ if c.is_ascii_digit() || c == '.' => {
+				while index + 1 < chars.len() && (chars[index + 1].is_ascii_digit() || chars[index + 1] == '.') {
+					index += 1;
+				}
+				let literal = chars[start..=index].iter().collect::<String>();
+				match literal.parse() {
+					Ok(value) => Token::Number(value),
+					Err(_) => return Err(format!("Malformed number \"{}\" at position {}.", literal, start))
+				}
+			},
+			_ if c.is_alphabetic() => {
+				while index + 1 < chars.len() && (chars[index + 1].is_alphanumeric() || chars[index + 1] == '_') {
+					index += 1;
+				}
+				Token::Identifier(chars[start..=index].iter().collect())
+			},
+			_ => return Err(format!("Unexpected character '{}' at position {}.", c, start))
+		};
+		tokens.push((token, start));
+		index += 1;
+	}
+	Ok(tokens)
+}
+
+/// Binding strengths of the binary operators, and of unary minus.
+const ADDITIVE: u8 = 1;
+const MULTIPLICATIVE: u8 = 2;
+const UNARY: u8 = 3;
+const EXPONENTIAL: u8 = 4;
+
+/// The number of arguments taken by the function `name`, if there is such a function.
+fn arity(name: &str) -> Option<usize> {
+	match name {
+		"sin" | "cos" | "tan" | "asin" | "acos" | "atan" | "exp" | "ln" | "sqrt" | "abs" |
+		"sinh" | "cosh" | "tanh" | "sec" | "csc" | "cot" | "factorial" | "floor" | "ceil" | "sign" | "round" | "trunc" | "fract" | "sigmoid" | "radians" | "degrees" => Some(1),
+		"mod" | "log" | "root" | "max" | "min" | "powi" | "derivative" | "atan2" | "binomial" => Some(2),
+		"clamp" => Some(3),
+		"sum" | "product" => Some(4),
+		_ => None
+	}
+}
+
+/// Applies the function `name` to `arguments`, of which there must be as many as its arity.
+///
+/// `position` is the position of the function's name, for error messages.
+fn apply_function(name: &str, arguments: Vec<Term>, position: usize) -> Result<Term, String> {
+	let mut arguments = arguments.into_iter().map(Box::new);
+	let mut argument = || arguments.next().unwrap();
+	Ok(match name {
+		"sin" => Term::Sine(argument()),
+		"cos" => Term::Cosine(argument()),
+		"tan" => Term::Tangent(argument()),
+		"asin" => Term::ArcSine(argument()),
+		"acos" => Term::ArcCosine(argument()),
+		"atan" => Term::ArcTangent(argument()),
+		"exp" => Term::Exp(argument()),
+		"ln" => Term::NaturalLog(argument()),
+		"log" => Term::Logarithm(argument(), argument()),
+		"sqrt" => Term::SquareRoot(argument()),
+		"root" => Term::NthRoot(argument(), argument()),
+		"abs" => Term::AbsoluteValue(argument()),
+		"sinh" => Term::Sinh(argument()),
+		"cosh" => Term::Cosh(argument()),
+		"tanh" => Term::Tanh(argument()),
+		"sec" => Term::Secant(argument()),
+		"csc" => Term::Cosecant(argument()),
+		"cot" => Term::Cotangent(argument()),
+		"mod" => Term::Modulo(argument(), argument()),
+		"factorial" => Term::Factorial(argument()),
+		"sum" | "product" => {
+			let body = argument();
+			let index = match *argument() {
+				Term::Variable(ref variable) => variable.symbol.clone(),
+				ref term => return Err(format!("Expected an index variable for \"{}\" at position {} (found '{}').", name, position, term))
+			};
+			if name == "sum" {
+				Term::Summation { index, from: argument(), to: argument(), body }
+			} else {
+				Term::IndexedProduct { index, from: argument(), to: argument(), body }
+			}
+		},
+		"floor" => Term::Floor(argument()),
+		"ceil" => Term::Ceiling(argument()),
+		"sign" => Term::Sign(argument()),
+		"max" => Term::Maximum(vec!(*argument(), *argument())),
+		"min" => Term::Minimum(vec!(*argument(), *argument())),
+		"round" => Term::Round(argument()),
+		"trunc" => Term::Truncate(argument()),
+		"fract" => Term::Fractional(argument()),
+		"clamp" => Term::Clamp { value: argument(), min: argument(), max: argument() },
+		"derivative" => {
+			let body = argument();
+			match *argument() {
+				Term::Variable(ref variable) => Term::Derivative { body, variable: variable.symbol.clone() },
+				ref term => return Err(format!("Expected a variable for \"{}\" at position {} (found '{}').", name, position, term))
+			}
+		},
+		"powi" => {
+			let base = argument();
+			match *argument() {
+				Term::Constant(exponent) if exponent.fract() == 0.0 && exponent.abs() <= f64::from(i32::MAX) => Term::PowerInt(base, exponent as i32),
+				ref term => return Err(format!("Expected an integer exponent for \"{}\" at position {} (found '{}').", name, position, term))
+			}
+		},
+		"atan2" => Term::Atan2 { y: argument(), x: argument() },
+		"sigmoid" => Term::Sigmoid(argument()),
+		"radians" => Term::ToRadians(argument()),
+		"degrees" => Term::ToDegrees(argument()),
+		"binomial" => Term::Binomial { n: argument(), k: argument() },
+		_ => unreachable!()
+	})
+}
+
+/// A precedence-climbing parser over a token stream.
+struct Parser {
+	tokens: Vec<(Token, usize)>,
+	index: usize,
+	end: usize
+}
+
+impl Parser {
+	fn peek(&self) -> Option<&Token> {
+		self.tokens.get(self.index).map(|(token, _)| token)
+	}
+
+	/// The position of the next token (or the end of input), for error messages.
+	fn position(&self) -> usize {
+		self.tokens.get(self.index).map(|&(_, position)| position).unwrap_or(self.end)
+	}
+
+	fn next(&mut self) -> Option<Token> {
+		let token = self.peek().cloned();
+		self.index += 1;
+		token
+	}
+
+	fn expect(&mut self, expected: Token, description: &str) -> Result<(), String> {
+		let position = self.position();
+		match self.next() {
+			Some(ref token) if *token == expected => Ok(()),
+			Some(token) => Err(format!("Expected {} at position {} (found '{}').", description, position, token)),
+			None => Err(format!("Expected {} at position {} (found end of input).", description, position))
+		}
+	}
+
+	/// Parses an expression containing only operators binding at least as tightly as `minimum`.
+	fn expression(&mut self, minimum: u8) -> Result<Term, String> {
+		let mut left = self.unary()?;
+		while let Some(&Token::Operator(operator)) = self.peek() {
+			let (precedence, right_associative) = match operator {
+				'+' | '-' => (ADDITIVE, false),
+				'*' | '/' => (MULTIPLICATIVE, false),
+				_ => (EXPONENTIAL, true)
+			};
+			if precedence < minimum {
+				break;
+			}
+			self.next();
+			let right = self.expression(if right_associative { precedence } else { precedence + 1 })?;
+			left = match operator {
+				'+' => left + right,
+				'-' => left - right,
+				'*' => left * right,
+				'/' => left / right,
+				_ => Term::Power(Box::new(left), Box::new(right))
+			};
+		}
+		Ok(left)
+	}
+
+	/// Parses an operand, possibly preceded by unary minus.
+	fn unary(&mut self) -> Result<Term, String> {
+		if let Some(&Token::Operator('-')) = self.peek() {
+			self.next();
+			return Ok(match self.expression(UNARY)? {
+				Term::Constant(value) => Term::Constant(-value),
+				term => -term
+			});
+		}
+		self.primary()
+	}
+
+	/// Parses a number, variable, function application, or parenthesized expression.
+	fn primary(&mut self) -> Result<Term, String> {
+		let position = self.position();
+		match self.next() {
+			Some(Token::Number(value)) => Ok(Term::Constant(value)),
+			Some(Token::LeftParenthesis) => {
+				let term = self.expression(ADDITIVE)?;
+				self.expect(Token::RightParenthesis, "')'")?;
+				Ok(term)
+			},
+			Some(Token::Identifier(name)) => {
+				if let Some(&Token::LeftParenthesis) = self.peek() {
+					self.next();
+					self.function(&name, position)
+				} else {
+					Ok(match name.as_str() {
+						"pi" | "π" => Term::Pi,
+						"e" => Term::E,
+						_ => Term::Variable(Variable::named(name))
+					})
+				}
+			},
+			Some(token) => Err(format!("Unexpected '{}' at position {}.", token, position)),
+			None => Err(format!("Unexpected end of input at position {}.", position))
+		}
+	}
+
+	/// Parses the arguments of the function `name` (whose opening parenthesis has already been consumed).
+	fn function(&mut self, name: &str, position: usize) -> Result<Term, String> {
+		if name == "piecewise" {
+			return self.piecewise();
+		}
+		let mut arguments = vec!(self.expression(ADDITIVE)?);
+		while let Some(&Token::Comma) = self.peek() {
+			self.next();
+			arguments.push(self.expression(ADDITIVE)?);
+		}
+		self.expect(Token::RightParenthesis, "')'")?;
+		// The maximum and minimum take any (positive) number of arguments.
+		match name {
+			"max" => return Ok(Term::Maximum(arguments)),
+			"min" => return Ok(Term::Minimum(arguments)),
+			_ => ()
+		}
+		let arity = match arity(name) {
+			Some(arity) => arity,
+			None => return Err(format!("Unknown function \"{}\" at position {}.", name, position))
+		};
+		if arguments.len() != arity {
+			return Err(format!("Function \"{}\" at position {} takes {} argument(s) ({} given).", name, position, arity, arguments.len()));
+		}
+		apply_function(name, arguments, position)
+	}
+
+	/// Parses the branches of a piecewise term, as alternating conditions and terms (whose opening parenthesis has already been consumed).
+	fn piecewise(&mut self) -> Result<Term, String> {
+		let mut branches = vec!();
+		loop {
+			let condition = self.condition()?;
+			self.expect(Token::Comma, "','")?;
+			branches.push((condition, self.expression(ADDITIVE)?));
+			if let Some(&Token::Comma) = self.peek() {
+				self.next();
+			} else {
+				break;
+			}
+		}
+		self.expect(Token::RightParenthesis, "')'")?;
+		Ok(Term::Piecewise(branches))
+	}
+
+	/// Parses a comparison between two expressions.
+	fn condition(&mut self) -> Result<Condition, String> {
+		let left = self.expression(ADDITIVE)?;
+		let position = self.position();
+		let comparison = match self.next() {
+			Some(Token::Comparison(comparison)) => comparison,
+			Some(token) => return Err(format!("Expected a comparison at position {} (found '{}').", position, token)),
+			None => return Err(format!("Expected a comparison at position {} (found end of input).", position))
+		};
+		let right = self.expression(ADDITIVE)?;
+		Ok(match comparison {
+			">" => Condition::GreaterThan(left, right),
+			">=" => Condition::GreaterThanOrEqual(left, right),
+			"<" => Condition::LessThan(left, right),
+			"<=" => Condition::LessThanOrEqual(left, right),
+			"==" => Condition::Equal(left, right),
+			_ => Condition::NotEqual(left, right)
+		})
+	}
+}
+
+impl FromStr for Term {
+	type Err = String;
+	/// Terms may be parsed from infix expressions.
+	///
+	/// The usual arithmetic operators (`+`, `-`, `*`, `/`, and `^` for exponentiation) are supported with their conventional precedence, along with unary minus, parentheses, numeric literals, the constants `pi` (or `π`) and `e`, variables (a letter followed by any letters, digits, or underscores), and the functions `sin`, `cos`, `tan`, `asin`, `acos`, `atan`, `atan2(y, x)`, `radians`, `degrees`, `sec`, `csc`, `cot`, `sinh`, `cosh`, `tanh`, `exp`, `ln`, `sigmoid`, `sqrt`, `abs`, `factorial`, `binomial(n, k)`, `floor`, `ceil`, `round`, `trunc`, `fract`, `sign`, `log(x, base)`, `root(x, n)`, `powi(x, n)` (for a literal integer `n`), `mod(a, b)`, `max(a, b, ...)`, `min(a, b, ...)`, `clamp(x, min, max)`, `derivative(term, variable)`, `sum(body, index, from, to)`, and `product(body, index, from, to)`. Piecewise terms are written `piecewise(condition, term, ...)`, where each condition compares two expressions with one of `>`, `>=`, `<`, `<=`, `==`, or `!=`.
+	///
+	/// Errors describe the problem and the (character) position at which it was found.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "2 + 3 * 4".parse().unwrap();
+	/// assert!((t.reduce().unwrap() - 14.0).abs() < 0.00001);
+	///
+	/// let t: Term = "2 * x + sin(y)".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 1.5);
+	/// values.insert("y".to_string(), 0.0);
+	/// assert!((t.evaluate(&values).unwrap() - 3.0).abs() < 0.00001);
+	///
+	/// let t: Term = "vel * t_1".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("vel".to_string(), 3.0);
+	/// values.insert("t_1".to_string(), 2.0);
+	/// assert!((t.evaluate(&values).unwrap() - 6.0).abs() < 0.00001);
+	///
+	/// let t: Term = "-(1 - 2) ^ 2 / (4 - 2)".parse().unwrap();
+	/// assert!((t.reduce().unwrap() + 0.5).abs() < 0.00001);
+	///
+	/// let t: Term = "cos(2 * pi) + ln(e)".parse().unwrap();
+	/// assert!((t.reduce().unwrap() - 2.0).abs() < 0.00001);
+	///
+	/// let t: Term = "piecewise(x >= 0, x, x < 0, -x)".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), -2.0);
+	/// assert!((t.evaluate(&values).unwrap() - 2.0).abs() < 0.00001);
+	///
+	/// assert!("(1 + 2".parse::<Term>().is_err());
+	/// assert!("1 + 2)".parse::<Term>().is_err());
+	/// assert!("1 +".parse::<Term>().is_err());
+	/// ```
+	fn from_str(s: &str) -> Result<Term, Self::Err> {
+		let mut parser = Parser { tokens: tokenize(s)?, index: 0, end: s.chars().count() };
+		let term = parser.expression(ADDITIVE)?;
+		match parser.peek() {
+			None => Ok(term),
+			Some(token) => Err(format!("Unexpected '{}' at position {}.", token, parser.position()))
+		}
+	}
+}
+
+impl<'a> TryFrom<&'a str> for Term {
+	type Error = String;
+	/// Terms may be converted from infix expressions, exactly as they are parsed by `Term::from_str`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	/// use std::convert::TryFrom;
+	///
+	/// let t = Term::try_from("x + 1").unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// assert_eq!(t.evaluate(&values), Ok(3.0));
+	///
+	/// assert!(Term::try_from("x +").is_err());
+	/// ```
+	fn try_from(s: &'a str) -> Result<Term, Self::Error> {
+		s.parse()
+	}
+}
+
+impl Term {
+	/// Parses a term from a sequence of tokens in Reverse Polish (postfix) Notation.
+	///
+	/// Tokens are processed in order using a stack: numbers, variables, and the constants `pi` (or `π`) and `e` are pushed onto the stack, while the binary operators (`+`, `-`, `*`, `/`, and `^`), negation (`neg`), and the functions supported by `Term::from_str` (except `piecewise`) replace as many terms at the top of the stack as they take operands with the result of applying them. A function's arguments are taken in the order they were pushed, so `x 2 log` is `log(x, 2)`. Exactly one term must remain on the stack at the end.
+	///
+	/// Errors describe the problem and the index of the offending token.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t = Term::from_rpn(&["3", "4", "+"]).unwrap();
+	/// assert_eq!(t.reduce(), Ok(7.0));
+	///
+	/// let t = Term::from_rpn(&["x", "2", "^", "sin", "1", "-"]).unwrap();
+	/// assert_eq!(t, "sin(x^2) - 1".parse().unwrap());
+	///
+	/// assert_eq!(Term::from_rpn(&["-2.5", "x", "*"]).unwrap(), Term::Constant(-2.5) * Term::from('x'));
+	/// assert!(Term::from_rpn(&["3", "+"]).is_err());
+	/// assert!(Term::from_rpn(&["3", "4"]).is_err());
+	/// ```
+	pub fn from_rpn(tokens: &[&str]) -> Result<Term, String> {
+		let mut stack: Vec<Term> = vec!();
+		for (position, &token) in tokens.iter().enumerate() {
+			let arity = match token {
+				"+" | "-" | "*" | "/" | "^" => 2,
+				"neg" => 1,
+				_ => arity(token).unwrap_or(0)
+			};
+			if stack.len() < arity {
+				return Err(format!("\"{}\" at token {} takes {} operand(s) ({} available).", token, position, arity, stack.len()));
+			}
+			let operands = stack.split_off(stack.len() - arity);
+			let term = if arity > 0 {
+				let mut operands = operands.into_iter();
+				let mut operand = || operands.next().unwrap();
+				match token {
+					"+" => operand() + operand(),
+					"-" => operand() - operand(),
+					"*" => operand() * operand(),
+					"/" => operand() / operand(),
+					"^" => Term::Power(Box::new(operand()), Box::new(operand())),
+					"neg" => -operand(),
+					_ => apply_function(token, operands.collect(), position)?
+				}
+			} else {
+				match (tokenize(token), token.parse()) {
+					(Ok(ref tokens), _) if tokens.len() == 1 && matches!(tokens[0].0, Token::Identifier(_)) => match token {
+						"pi" | "π" => Term::Pi,
+						"e" => Term::E,
+						_ => Term::Variable(Variable::named(token))
+					},
+					(_, Ok(value)) => Term::Constant(value),
+					_ => return Err(format!("Unexpected \"{}\" at token {}.", token, position))
+				}
+			};
+			stack.push(term);
+		}
+		match stack.len() {
+			1 => Ok(stack.remove(0)),
+			0 => Err("No terms given.".to_string()),
+			count => Err(format!("{} terms left on the stack (expected 1).", count))
+		}
+	}
+}
+
+impl Term {
+	/// Converts a term into a sequence of tokens in Reverse Polish (postfix) Notation, as accepted by `Term::from_rpn`.
+	///
+	/// Operands always precede the operators and functions applied to them. Operators are rendered as `+`, `-`, `*`, `/`, and `^` (with negation as `neg`), functions by their names, constants in decimal (or as `pi` and `e`), and variables by their symbols. Sums, differences, products, quotients, maxima, and minima of more than two operands are applied pairwise, from left to right.
+	///
+	/// For terms built by the arithmetic operators, `Term::from_rpn` recovers a structurally equal term. Quotients of more than two operands are recovered as nested quotients (with the same value), and piecewise terms, which have no postfix form, are rendered as a single token containing their infix form. Empty differences and quotients have neither an infix nor a postfix form, and are rendered as a single empty token, which `Term::from_rpn` rejects (just as evaluating them produces an error).
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "(3 + 4) * 2".parse().unwrap();
+	/// assert_eq!(t.to_postfix(), vec!("3", "4", "+", "2", "*"));
+	///
+	/// let t: Term = "-sin(x)^2 + log(y, 2) - 1".parse().unwrap();
+	/// let tokens = t.to_postfix();
+	/// assert_eq!(tokens, vec!("x", "sin", "2", "^", "neg", "y", "2", "log", "+", "1", "-"));
+	/// let tokens = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+	/// assert_eq!(Term::from_rpn(&tokens), Ok(t));
+	///
+	/// let t = Term::Difference(vec!()) * Term::Quotient(vec!());
+	/// assert!(t.reduce().is_err());
+	/// let tokens = t.to_postfix();
+	/// assert_eq!(tokens, vec!("", "", "*"));
+	/// let tokens = tokens.iter().map(String::as_str).collect::<Vec<_>>();
+	/// assert!(Term::from_rpn(&tokens).is_err());
+	/// ```
+	pub fn to_postfix(&self) -> Vec<String> {
+		let mut tokens = vec!();
+		self.write_postfix(&mut tokens);
+		tokens
+	}
+
+	fn write_postfix(&self, tokens: &mut Vec<String>) {
+		use Term::*;
+		let operator = match *self {
+			Sum(ref terms) | Difference(ref terms) | Product(ref terms) | Quotient(ref terms) | Maximum(ref terms) | Minimum(ref terms) => {
+				let operator = match *self {
+					Sum(_) => "+",
+					Difference(_) => "-",
+					Product(_) => "*",
+					Quotient(_) => "/",
+					_ => self.function_name().unwrap_or_default()
+				};
+				if terms.is_empty() {
+					tokens.push(format!("{}", self));
+				}
+				for (index, term) in terms.iter().enumerate() {
+					term.write_postfix(tokens);
+					if index > 0 {
+						tokens.push(operator.to_string());
+					}
+				}
+				return;
+			},
+			Constant(_) | Variable(_) | Piecewise(_) => {
+				tokens.push(format!("{}", self));
+				return;
+			},
+			Summation { ref index, ref from, ref to, ref body } | IndexedProduct { ref index, ref from, ref to, ref body } => {
+				body.write_postfix(tokens);
+				tokens.push(index.clone());
+				from.write_postfix(tokens);
+				to.write_postfix(tokens);
+				tokens.push(self.function_name().unwrap_or_default().to_string());
+				return;
+			},
+			Derivative { ref body, ref variable } => {
+				body.write_postfix(tokens);
+				tokens.push(variable.clone());
+				tokens.push(self.function_name().unwrap_or_default().to_string());
+				return;
+			},
+			PowerInt(ref base, exponent) => {
+				base.write_postfix(tokens);
+				tokens.push(exponent.to_string());
+				tokens.push(self.function_name().unwrap_or_default().to_string());
+				return;
+			},
+			Pi => "pi",
+			E => "e",
+			Power(_, _) => "^",
+			Negation(_) => "neg",
+			_ => self.function_name().unwrap_or_default()
+		};
+		for child in self.children() {
+			child.write_postfix(tokens);
+		}
+		tokens.push(operator.to_string());
+	}
+}
+
+/// Splits an s-expression into parentheses and atoms (as `Token::Identifier`s), each paired with the (character) position at which it starts.
+fn tokenize_sexp(s: &str) -> Vec<(Token, usize)> {
+	let mut tokens = vec!();
+	let mut atom: Option<(String, usize)> = None;
+	for (position, c) in s.chars().enumerate() {
+		if c == '(' || c == ')' || c.is_whitespace() {
+			if let Some((atom, start)) = atom.take() {
+				tokens.push((Token::Identifier(atom), start));
+			}
+			match c {
+				'(' => tokens.push((Token::LeftParenthesis, position)),
+				')' => tokens.push((Token::RightParenthesis, position)),
+				_ => ()
+			}
+		} else {
+			atom.get_or_insert_with(|| (String::new(), position)).0.push(c);
+		}
+	}
+	if let Some((atom, start)) = atom {
+		tokens.push((Token::Identifier(atom), start));
+	}
+	tokens
+}
+
+impl Parser {
+	/// Parses a term written as an s-expression (see `Term::from_sexp`).
+	fn sexp(&mut self) -> Result<Term, String> {
+		let position = self.position();
+		match self.next() {
+			Some(Token::Identifier(atom)) => match atom.as_str() {
+				"pi" | "π" => Ok(Term::Pi),
+				"e" => Ok(Term::E),
+				_ => match (atom.parse(), atom.parse()) {
+					(Ok(value), _) if !atom.starts_with(char::is_alphabetic) => Ok(Term::Constant(value)),
+					(_, Ok(variable)) => Ok(Term::Variable(variable)),
+					_ => Err(format!("Unexpected '{}' at position {}.", atom, position))
+				}
+			},
+			Some(Token::LeftParenthesis) => {
+				let position = self.position();
+				let head = match self.next() {
+					Some(Token::Identifier(head)) => head,
+					Some(token) => return Err(format!("Expected an operator or function at position {} (found '{}').", position, token)),
+					None => return Err(format!("Expected an operator or function at position {} (found end of input).", position))
+				};
+				if head == "piecewise" {
+					return self.sexp_piecewise();
+				}
+				let mut operands = vec!();
+				while self.peek().is_some_and(|token| *token != Token::RightParenthesis) {
+					operands.push(self.sexp()?);
+				}
+				self.expect(Token::RightParenthesis, "')'")?;
+				let arity = match head.as_str() {
+					"+" => return Ok(Term::Sum(operands)),
+					"-" => return Ok(Term::Difference(operands)),
+					"*" => return Ok(Term::Product(operands)),
+					"/" => return Ok(Term::Quotient(operands)),
+					"max" => return Ok(Term::Maximum(operands)),
+					"min" => return Ok(Term::Minimum(operands)),
+					"^" => 2,
+					"neg" => 1,
+					name => match arity(name) {
+						Some(arity) => arity,
+						None => return Err(format!("Unknown function \"{}\" at position {}.", name, position))
+					}
+				};
+				if operands.len() != arity {
+					return Err(format!("Function \"{}\" at position {} takes {} argument(s) ({} given).", head, position, arity, operands.len()));
+				}
+				let mut operands = operands.into_iter();
+				match head.as_str() {
+					"^" => Ok(Term::Power(Box::new(operands.next().unwrap()), Box::new(operands.next().unwrap()))),
+					"neg" => Ok(Term::Negation(Box::new(operands.next().unwrap()))),
+					_ => apply_function(&head, operands.collect(), position)
+				}
+			},
+			Some(token) => Err(format!("Unexpected '{}' at position {}.", token, position)),
+			None => Err(format!("Unexpected end of input at position {}.", position))
+		}
+	}
+
+	/// Parses the branches of a piecewise s-expression, as alternating conditions and terms (whose head has already been consumed).
+	fn sexp_piecewise(&mut self) -> Result<Term, String> {
+		let mut branches = vec!();
+		while self.peek().is_some_and(|token| *token != Token::RightParenthesis) {
+			self.expect(Token::LeftParenthesis, "'('")?;
+			let position = self.position();
+			let comparison = match self.next() {
+				Some(Token::Identifier(comparison)) => comparison,
+				Some(token) => return Err(format!("Expected a comparison at position {} (found '{}').", position, token)),
+				None => return Err(format!("Expected a comparison at position {} (found end of input).", position))
+			};
+			let (left, right) = (self.sexp()?, self.sexp()?);
+			self.expect(Token::RightParenthesis, "')'")?;
+			let condition = match comparison.as_str() {
+				">" => Condition::GreaterThan(left, right),
+				">=" => Condition::GreaterThanOrEqual(left, right),
+				"<" => Condition::LessThan(left, right),
+				"<=" => Condition::LessThanOrEqual(left, right),
+				"==" => Condition::Equal(left, right),
+				"!=" => Condition::NotEqual(left, right),
+				_ => return Err(format!("Expected a comparison at position {} (found '{}').", position, comparison))
+			};
+			branches.push((condition, self.sexp()?));
+		}
+		self.expect(Token::RightParenthesis, "')'")?;
+		Ok(Term::Piecewise(branches))
+	}
+}
+
+impl Term {
+	/// Converts a term into an s-expression, as accepted by `Term::from_sexp`.
+	///
+	/// Every compound term is written as a parenthesized list of its operator or function followed by its operands, so no precedence rules are needed to read it back. Operators are written as `+`, `-`, `*`, `/`, and `^` (with negation as `neg`), functions by their names (with their arguments in the same order as in `Term::from_str`), constants in decimal (or as `pi` and `e`), and variables by their symbols. Sums, differences, products, quotients, maxima, and minima take all of their operands at once. Piecewise terms list their branches as alternating conditions, such as `(< x 0)`, and terms.
+	///
+	/// Variable assumptions are not represented.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "2 * x + sin(y)".parse().unwrap();
+	/// assert_eq!(&t.to_sexp(), "(+ (* 2 x) (sin y))");
+	///
+	/// let t: Term = "-x^2 / (1 - log(y, 2) - pi)".parse().unwrap();
+	/// assert_eq!(&t.to_sexp(), "(/ (neg (^ x 2)) (- 1 (log y 2) pi))");
+	/// ```
+	pub fn to_sexp(&self) -> String {
+		use Term::*;
+		let (head, operands) = match *self {
+			Constant(value) => return value.to_string(),
+			Variable(ref variable) => return variable.symbol.clone(),
+			Pi => return "pi".to_string(),
+			E => return "e".to_string(),
+			Summation { ref index, ref from, ref to, ref body } | IndexedProduct { ref index, ref from, ref to, ref body } => {
+				(self.function_name().unwrap_or_default(), vec!(body.to_sexp(), index.clone(), from.to_sexp(), to.to_sexp()))
+			},
+			PowerInt(ref base, exponent) => (self.function_name().unwrap_or_default(), vec!(base.to_sexp(), exponent.to_string())),
+			Derivative { ref body, ref variable } => (self.function_name().unwrap_or_default(), vec!(body.to_sexp(), variable.clone())),
+			Piecewise(ref branches) => ("piecewise", branches.iter().flat_map(|(condition, term)| {
+				let (a, b) = condition.operands();
+				vec!(format!("({} {} {})", condition.symbol(), a.to_sexp(), b.to_sexp()), term.to_sexp())
+			}).collect()),
+			_ => {
+				let head = match *self {
+					Sum(_) => "+",
+					Difference(_) => "-",
+					Product(_) => "*",
+					Quotient(_) => "/",
+					Power(_, _) => "^",
+					Negation(_) => "neg",
+					_ => self.function_name().unwrap_or_default()
+				};
+				(head, self.children().into_iter().map(Term::to_sexp).collect::<Vec<_>>())
+			}
+		};
+		let mut parts = vec!(head.to_string());
+		parts.extend(operands);
+		format!("({})", parts.join(" "))
+	}
+
+	/// Parses a term from an s-expression, as produced by `Term::to_sexp`.
+	///
+	/// Atoms are numbers, variables, and the constants `pi` (or `π`) and `e`; lists are an operator or function (any of those written by `Term::to_sexp`) followed by its operands. Converting a term into an s-expression and back produces a structurally equal term, provided that its variables carry no assumptions and its constants are finite.
+	///
+	/// Errors describe the problem and the (character) position at which it was found.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t = Term::from_sexp("(+ (* 2 x) (sin y))").unwrap();
+	/// assert_eq!(t, "2 * x + sin(y)".parse().unwrap());
+	///
+	/// let t: Term = "piecewise(x < 0, -x, x >= 0, max(x, 1, y)) ^ sum(k^2, k, 1, n) - ln(e)".parse().unwrap();
+	/// assert_eq!(Term::from_sexp(&t.to_sexp()), Ok(t));
+	///
+	/// assert!(Term::from_sexp("(+ 1 2").is_err());
+	/// assert!(Term::from_sexp("(sin 1 2)").is_err());
+	/// assert!(Term::from_sexp("(1 2)").is_err());
+	/// ```
+	pub fn from_sexp(s: &str) -> Result<Term, String> {
+		let mut parser = Parser { tokens: tokenize_sexp(s), index: 0, end: s.chars().count() };
+		let term = parser.sexp()?;
+		match parser.peek() {
+			None => Ok(term),
+			Some(token) => Err(format!("Unexpected '{}' at position {}.", token, parser.position()))
+		}
+	}
+}
@@ -0,0 +1,207 @@
+use term::Term;
+use variable::Variable;
+
+impl Term {
+	/// Whether a term is a polynomial in the variable `symbol`.
+	///
+	/// A term is a polynomial in `symbol` if it is built from sums, differences, products, and negations of terms not involving `symbol`, `symbol` itself, and powers of such polynomials to constant non-negative integer exponents. Other variables are treated as constants, and division is permitted only by terms not involving `symbol`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x^2 + 3 * x + 1".parse().unwrap();
+	/// assert!(t.is_polynomial_in("x"));
+	/// let t: Term = "sin(y) * x / 2".parse().unwrap();
+	/// assert!(t.is_polynomial_in("x"));
+	///
+	/// let t: Term = "sin(x)".parse().unwrap();
+	/// assert!(!t.is_polynomial_in("x"));
+	/// let t: Term = "1 / x".parse().unwrap();
+	/// assert!(!t.is_polynomial_in("x"));
+	/// let t: Term = "x^0.5".parse().unwrap();
+	/// assert!(!t.is_polynomial_in("x"));
+	/// ```
+	pub fn is_polynomial_in(&self, symbol: &str) -> bool {
+		self.degree(symbol).is_some()
+	}
+
+	/// The degree of a term as a polynomial in the variable `symbol`, or `None` if it is not one (as in `Term::is_polynomial_in`).
+	///
+	/// Terms not involving `symbol` (including bare constants) have degree zero. The degree is that of the term as written, so terms which cancel out (as in `x - x`) still count towards it.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x^3 + x".parse().unwrap();
+	/// assert_eq!(t.degree("x"), Some(3));
+	/// let t: Term = "(x * y + 1)^2".parse().unwrap();
+	/// assert_eq!(t.degree("x"), Some(2));
+	/// assert_eq!(Term::Constant(5.0).degree("x"), Some(0));
+	///
+	/// let t: Term = "x^2 + exp(x)".parse().unwrap();
+	/// assert_eq!(t.degree("x"), None);
+	/// ```
+	pub fn degree(&self, symbol: &str) -> Option<u32> {
+		use Term::*;
+		if !self.depends_on(symbol) {
+			return Some(0);
+		}
+		match *self {
+			Variable(_) => Some(1),
+			Sum(ref terms) | Difference(ref terms) => terms.iter().try_fold(0, |degree, term| term.degree(symbol).map(|other| degree.max(other))),
+			Product(ref terms) => terms.iter().try_fold(0u32, |degree, term| degree.checked_add(term.degree(symbol)?)),
+			Quotient(ref terms) => match terms.split_first() {
+				Some((dividend, divisors)) if divisors.iter().all(|divisor| !divisor.depends_on(symbol)) => dividend.degree(symbol),
+				_ => None
+			},
+			Negation(ref term) => term.degree(symbol),
+			Power(ref base, ref exponent) => base.degree(symbol)?.checked_mul(natural_exponent(exponent, symbol)?),
+			PowerInt(ref base, exponent) if exponent >= 0 => base.degree(symbol)?.checked_mul(exponent as u32),
+			_ => None
+		}
+	}
+
+	/// The numeric coefficients of a term as a polynomial in the variable `symbol`, indexed by power (so that the constant term comes first).
+	///
+	/// Returns `None` if the term is not a polynomial in `symbol` (as in `Term::is_polynomial_in`), or if any of its coefficients is not numeric (for example, because it involves another variable). Trailing zero coefficients (as from terms which cancel out) are dropped, though the zero polynomial still has the single coefficient `0`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "2 * x^2 + 3 * x + 1".parse().unwrap();
+	/// assert_eq!(t.coefficients("x"), Some(vec!(1.0, 3.0, 2.0)));
+	/// let t: Term = "(x - 1) * (x + 1) - x^2".parse().unwrap();
+	/// assert_eq!(t.coefficients("x"), Some(vec!(-1.0)));
+	///
+	/// let t: Term = "y * x + 1".parse().unwrap();
+	/// assert_eq!(t.coefficients("x"), None);
+	/// let t: Term = "sqrt(x)".parse().unwrap();
+	/// assert_eq!(t.coefficients("x"), None);
+	/// ```
+	pub fn coefficients(&self, symbol: &str) -> Option<Vec<f64>> {
+		let mut coefficients = self.polynomial_coefficients(symbol)?;
+		while coefficients.len() > 1 && coefficients.last() == Some(&0.0) {
+			coefficients.pop();
+		}
+		Some(coefficients)
+	}
+
+	/// Rewrites a polynomial in the variable `symbol` in Horner form, as nested products and sums such as `((x + 2) * x + 3) * x + 4`.
+	///
+	/// Evaluating the Horner form takes only one multiplication and one addition per coefficient, and avoids the rounding error of computing each power separately. Returns `None` if the term does not have numeric coefficients as a polynomial in `symbol` (see `Term::coefficients`). Zero coefficients are omitted, as is a leading coefficient of one.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x^3 + 2 * x^2 + 3 * x + 4".parse().unwrap();
+	/// let horner = t.horner_form("x").unwrap();
+	/// assert_eq!(&format!("{}", horner), "((x + 2) * x + 3) * x + 4");
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// assert_eq!(horner.evaluate(&values), t.evaluate(&values));
+	///
+	/// let t: Term = "3 * x^2 - 1".parse().unwrap();
+	/// assert_eq!(&format!("{}", t.horner_form("x").unwrap()), "3 * x * x + -1");
+	/// let t: Term = "sin(x)".parse().unwrap();
+	/// assert_eq!(t.horner_form("x"), None);
+	/// ```
+	pub fn horner_form(&self, symbol: &str) -> Option<Term> {
+		let mut coefficients = self.coefficients(symbol)?;
+		let variable = Term::Variable(Variable::named(symbol));
+		let mut horner = Term::Constant(coefficients.pop()?);
+		for coefficient in coefficients.into_iter().rev() {
+			horner = if horner.is_one() { variable.clone() } else { Term::Product(vec!(horner, variable.clone())) };
+			if coefficient != 0.0 {
+				horner = Term::Sum(vec!(horner, Term::Constant(coefficient)));
+			}
+		}
+		Some(horner)
+	}
+
+	/// Computes the (untrimmed) coefficients for `Term::coefficients`.
+	fn polynomial_coefficients(&self, symbol: &str) -> Option<Vec<f64>> {
+		use Term::*;
+		if !self.depends_on(symbol) {
+			return self.reduce().ok().map(|value| vec!(value));
+		}
+		match *self {
+			Variable(_) => Some(vec!(0.0, 1.0)),
+			Sum(ref terms) => terms.iter().try_fold(vec!(0.0), |sum, term| Some(add(&sum, &term.polynomial_coefficients(symbol)?, 1.0))),
+			Difference(ref terms) => {
+				let (first, rest) = terms.split_first()?;
+				rest.iter().try_fold(first.polynomial_coefficients(symbol)?, |difference, term| Some(add(&difference, &term.polynomial_coefficients(symbol)?, -1.0)))
+			},
+			Product(ref terms) => terms.iter().try_fold(vec!(1.0), |product, term| Some(multiply(&product, &term.polynomial_coefficients(symbol)?))),
+			Quotient(ref terms) => {
+				let (dividend, divisors) = terms.split_first()?;
+				let mut divisor = 1.0;
+				for term in divisors {
+					if term.depends_on(symbol) {
+						return None;
+					}
+					divisor *= term.reduce().ok()?;
+				}
+				if divisor == 0.0 {
+					return None;
+				}
+				Some(dividend.polynomial_coefficients(symbol)?.into_iter().map(|coefficient| coefficient / divisor).collect())
+			},
+			Negation(ref term) => Some(term.polynomial_coefficients(symbol)?.into_iter().map(|coefficient| -coefficient).collect()),
+			Power(ref base, ref exponent) => {
+				let exponent = natural_exponent(exponent, symbol)?;
+				let base = base.polynomial_coefficients(symbol)?;
+				Some((0..exponent).fold(vec!(1.0), |power, _| multiply(&power, &base)))
+			},
+			PowerInt(ref base, exponent) if exponent >= 0 => {
+				let base = base.polynomial_coefficients(symbol)?;
+				Some((0..exponent).fold(vec!(1.0), |power, _| multiply(&power, &base)))
+			},
+			_ => None
+		}
+	}
+
+	/// Whether the variable `symbol` occurs freely in a term.
+	fn depends_on(&self, symbol: &str) -> bool {
+		self.free_variables().contains(symbol)
+	}
+}
+
+/// The value of `exponent`, if it is a non-negative integer not involving `symbol`.
+fn natural_exponent(exponent: &Term, symbol: &str) -> Option<u32> {
+	if exponent.depends_on(symbol) {
+		return None;
+	}
+	match exponent.reduce() {
+		Ok(value) if value >= 0.0 && value.fract() == 0.0 && value <= f64::from(u32::MAX) => Some(value as u32),
+		_ => None
+	}
+}
+
+/// Adds `scale` times the polynomial `b` to the polynomial `a` (both given by their coefficients).
+fn add(a: &[f64], b: &[f64], scale: f64) -> Vec<f64> {
+	let mut sum = a.to_vec();
+	if sum.len() < b.len() {
+		sum.resize(b.len(), 0.0);
+	}
+	for (coefficient, other) in sum.iter_mut().zip(b) {
+		*coefficient += scale * other;
+	}
+	sum
+}
+
+/// Multiplies the polynomials `a` and `b` (both given by their coefficients).
+fn multiply(a: &[f64], b: &[f64]) -> Vec<f64> {
+	let mut product = vec!(0.0; a.len() + b.len() - 1);
+	for (i, x) in a.iter().enumerate() {
+		for (j, y) in b.iter().enumerate() {
+			product[i + j] += x * y;
+		}
+	}
+	product
+}
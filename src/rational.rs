@@ -0,0 +1,177 @@
+use error::EvalError;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::collections::HashMap;
+use term::Term;
+
+type RationalValues = HashMap<String, BigRational>;
+
+/// Ensures that `value` is an integer, for `function`, which requires one.
+fn integer(function: &str, value: &BigRational) -> Result<(), EvalError> {
+	if value.is_integer() {
+		Ok(())
+	} else {
+		Err(EvalError::DomainError { function: function.to_string(), value: value.to_f64().unwrap_or(f64::NAN) })
+	}
+}
+
+impl Term {
+	/// Evaluates a term exactly, in arbitrary-precision rational arithmetic, given the (rational) values of its variables.
+	///
+	/// Constants are converted to rationals exactly, so `Term::Constant(0.1)` has the (exact) value of the `f64` nearest to one tenth, rather than exactly one tenth; exact fractions should instead be written as quotients, as in the example below. Sums, differences, products, quotients, negations, absolute values, moduli, integer powers, factorials of non-negative integers, floors, ceilings, rounding, integer and fractional parts, signs, maxima, minima, clamping, summations and indexed products, and piecewise terms are all evaluated exactly. Terms whose values are generally irrational (the constants π and e, and all other functions) produce `EvalError::Irrational`, even where a particular value happens to be rational.
+	///
+	/// This method is only available with the `rational` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{BigRational, EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "1 / 3 + 1 / 6".parse().unwrap();
+	/// let half = BigRational::new(1.into(), 2.into());
+	/// assert_eq!(t.evaluate_rational(&HashMap::new()), Ok(half.clone()));
+	///
+	/// let t: Term = "1 / 10 + 2 / 10".parse().unwrap();
+	/// assert_eq!(t.evaluate_rational(&HashMap::new()), Ok(BigRational::new(3.into(), 10.into())));
+	/// assert!(t.reduce().unwrap() != 0.3); // 0.30000000000000004
+	///
+	/// let t: Term = "x^2 - 1 / x".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), half);
+	/// assert_eq!(t.evaluate_rational(&values), Ok(BigRational::new((-7).into(), 4.into())));
+	///
+	/// let t: Term = "sqrt(4)".parse().unwrap();
+	/// assert_eq!(t.evaluate_rational(&values), Err(EvalError::Irrational("sqrt".to_string())));
+	/// ```
+	pub fn evaluate_rational(&self, values: &RationalValues) -> Result<BigRational, EvalError> {
+		self.evaluate_postorder(|term, operands| term.apply_rational(operands, values))
+	}
+
+	/// Computes the exact value of a term given the values of its children, in order (see `Term::apply`).
+	fn apply_rational(&self, operands: Vec<BigRational>, values: &RationalValues) -> Result<BigRational, EvalError> {
+		use Term::*;
+		if let Some((index, from, to, body)) = self.indexed() {
+			let summation = matches!(*self, Summation { .. });
+			let function = format!("{} bound", if summation { "summation" } else { "product" });
+			let (mut value, to) = (from.evaluate_rational(values)?, to.evaluate_rational(values)?);
+			integer(&function, &value)?;
+			integer(&function, &to)?;
+			let mut bindings = values.clone();
+			let mut result = if summation { BigRational::zero() } else { BigRational::one() };
+			while value <= to {
+				bindings.insert(index.to_string(), value.clone());
+				let term = body.evaluate_rational(&bindings)?;
+				result = if summation { result + term } else { result * term };
+				value += BigRational::one();
+			}
+			return Ok(result);
+		}
+		let mut operands = operands.into_iter();
+		let mut operand = || operands.next().unwrap();
+		match *self {
+			Constant(value) => BigRational::from_float(value).ok_or(EvalError::DomainError { function: "rational".to_string(), value }),
+			Variable(ref variable) => values.get(&variable.symbol).cloned().ok_or_else(|| EvalError::UndefinedVariable(variable.symbol.clone())),
+			Sum(_) => Ok(operands.fold(BigRational::zero(), |sum, value| sum + value)),
+			Difference(_) => {
+				let first = operands.next().ok_or_else(|| EvalError::NoOperands("difference".to_string()))?;
+				Ok(operands.fold(first, |difference, value| difference - value))
+			},
+			Product(_) => Ok(operands.fold(BigRational::one(), |product, value| product * value)),
+			Quotient(_) => {
+				let first = operands.next().ok_or_else(|| EvalError::NoOperands("quotient".to_string()))?;
+				operands.try_fold(first, |quotient, divisor| {
+					if divisor.is_zero() {
+						return Err(EvalError::DivisionByZero);
+					}
+					Ok(quotient / divisor)
+				})
+			},
+			Power(_, _) => {
+				let (base, exponent) = (operand(), operand());
+				let exponent = match exponent.to_integer().to_i32() {
+					Some(integer) if exponent.is_integer() => integer,
+					_ => return Err(EvalError::Irrational("^".to_string()))
+				};
+				if base.is_zero() && exponent < 0 {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(base.pow(exponent))
+			},
+			PowerInt(_, exponent) => {
+				let base = operand();
+				if base.is_zero() && exponent < 0 {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(base.pow(exponent))
+			},
+			AbsoluteValue(_) => Ok(operand().abs()),
+			Negation(_) => Ok(-operand()),
+			Modulo(_, _) => {
+				let (dividend, divisor) = (operand(), operand().abs());
+				if divisor.is_zero() {
+					return Err(EvalError::DivisionByZero);
+				}
+				let quotient = (&dividend / &divisor).floor();
+				Ok(dividend - divisor * quotient)
+			},
+			Factorial(_) => {
+				let value = operand();
+				integer("factorial", &value)?;
+				if value.is_negative() {
+					return Err(EvalError::DomainError { function: "factorial".to_string(), value: value.to_f64().unwrap_or(f64::NAN) });
+				}
+				let (mut factor, mut product) = (BigRational::one(), BigRational::one());
+				while factor <= value {
+					product *= &factor;
+					factor += BigRational::one();
+				}
+				Ok(product)
+			},
+			Binomial { .. } => {
+				let (n, k) = (operand(), operand());
+				integer("binomial", &n)?;
+				integer("binomial", &k)?;
+				if n.is_negative() {
+					return Err(EvalError::DomainError { function: "binomial".to_string(), value: n.to_f64().unwrap_or(f64::NAN) });
+				}
+				if k.is_negative() || k > n {
+					return Err(EvalError::DomainError { function: "binomial".to_string(), value: k.to_f64().unwrap_or(f64::NAN) });
+				}
+				let (mut coefficient, mut i) = (BigRational::one(), BigRational::one());
+				while i <= k {
+					coefficient = coefficient * (&n - &k + &i) / &i;
+					i += BigRational::one();
+				}
+				Ok(coefficient)
+			},
+			Derivative { ref body, ref variable } => body.derivative(variable).evaluate_rational(values),
+			Piecewise(ref branches) => {
+				for (condition, term) in branches {
+					let (a, b) = condition.operands();
+					if condition.compare(a.evaluate_rational(values)?, b.evaluate_rational(values)?) {
+						return term.evaluate_rational(values);
+					}
+				}
+				Err(EvalError::NoMatchingBranch)
+			},
+			Floor(_) => Ok(operand().floor()),
+			Ceiling(_) => Ok(operand().ceil()),
+			Sign(_) => Ok(operand().signum()),
+			Clamp { .. } => {
+				let (value, min, max) = (operand(), operand(), operand());
+				if min > max {
+					return Err(EvalError::DomainError { function: "clamp bounds".to_string(), value: min.to_f64().unwrap_or(f64::NAN) });
+				}
+				Ok(value.clamp(min, max))
+			},
+			Round(_) => Ok(operand().round()),
+			Truncate(_) => Ok(operand().trunc()),
+			Fractional(_) => Ok(operand().fract()),
+			Maximum(_) => operands.max().ok_or_else(|| EvalError::NoOperands("max".to_string())),
+			Minimum(_) => operands.min().ok_or_else(|| EvalError::NoOperands("min".to_string())),
+			Pi => Err(EvalError::Irrational("π".to_string())),
+			E => Err(EvalError::Irrational("e".to_string())),
+			_ => Err(EvalError::Irrational(self.function_name().unwrap_or_default().to_string()))
+		}
+	}
+}
@@ -0,0 +1,657 @@
+use std::cmp::Ordering;
+use std::f64::consts;
+use std::iter;
+use term::Term;
+
+impl Term {
+	/// Produces a simpler term with the same value.
+	///
+	/// Simplification proceeds bottom-up: constant subtrees are folded into a single `Term::Constant` (constant operands of a sum are combined where the first of them appeared, while those of a product are combined into a leading coefficient), nested sums and products are flattened, additive zeros and multiplicative ones are dropped, products containing zero collapse to zero, and compound terms left with a single operand are unwrapped.
+	///
+	/// Subtrees which cannot be evaluated (such as a division by zero) are left unfolded, so that the error surfaces on evaluation.
+	///
+	/// Sines, cosines, and tangents of special angles (multiples of `π / 6` or `π / 4`, written in terms of `Term::Pi`) are replaced by their exact values, rather than being folded with floating-point error.
+	///
+	/// Logistic functions written out as `1 / (1 + exp(-x))` are recognized as `Term::Sigmoid`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// assert_eq!((x.clone() + Term::Constant(0.0)).simplify(), x);
+	///
+	/// let p = Term::Constant(2.0) * Term::Constant(3.0) * x.clone() * Term::Constant(1.0);
+	/// assert_eq!(&format!("{}", p.simplify()), "6 * x");
+	///
+	/// let square = Term::Power(Box::new(x.clone()), Box::new(Term::Constant(2.0)));
+	/// assert_eq!(&format!("{}", square.derivative("x").simplify()), "2 * x");
+	///
+	/// assert_eq!(Term::Cosine(Box::new(Term::Pi)).simplify(), Term::Constant(-1.0));
+	/// assert_eq!(Term::Sine(Box::new(Term::Pi)).simplify(), Term::Constant(0.0));
+	/// let t: Term = "sin(pi / 6) + tan(-pi / 4)".parse().unwrap();
+	/// assert_eq!(t.simplify(), Term::Constant(-0.5));
+	///
+	/// let t: Term = "1 / (1 + exp(-x))".parse().unwrap();
+	/// assert_eq!(&format!("{}", t.simplify()), "sigmoid(x)");
+	/// ```
+	pub fn simplify(&self) -> Term {
+		use Term::*;
+		if let Some(value) = exact_trigonometric_value(self) {
+			return Constant(value);
+		}
+		let term = self.map_children(Term::simplify);
+		if !term.children().is_empty() && term.free_variables().is_empty() {
+			if let Ok(value) = term.reduce() {
+				if value.is_finite() {
+					return Constant(value);
+				}
+			}
+		}
+		match term {
+			Sum(terms) => {
+				let mut constant = 0.0;
+				let mut position = None;
+				let mut operands = vec!();
+				for term in flatten(terms, |term| match term { Sum(terms) => Ok(terms), term => Err(term) }) {
+					match term {
+						Constant(value) => {
+							constant += value;
+							position = position.or(Some(operands.len()));
+						},
+						term => operands.push(term)
+					}
+				}
+				if let Some(position) = position {
+					if constant != 0.0 {
+						operands.insert(position, Constant(constant));
+					}
+				}
+				collapse(operands, Sum, 0.0)
+			},
+			Product(terms) => {
+				let mut constant = 1.0;
+				let mut operands = vec!();
+				for term in flatten(terms, |term| match term { Product(terms) => Ok(terms), term => Err(term) }) {
+					match term {
+						Constant(value) => constant *= value,
+						term => operands.push(term)
+					}
+				}
+				if constant == 0.0 {
+					return Constant(0.0);
+				}
+				if constant != 1.0 {
+					operands.insert(0, Constant(constant));
+				}
+				collapse(operands, Product, 1.0)
+			},
+			Difference(terms) => {
+				let mut terms = terms.into_iter();
+				let first = match terms.next() {
+					Some(first) => first,
+					None => return Difference(vec!())
+				};
+				let mut operands = vec!(first);
+				operands.extend(terms.filter(|term| !is_constant(term, 0.0)));
+				if operands.len() == 1 {
+					operands.remove(0)
+				} else {
+					Difference(operands)
+				}
+			},
+			Quotient(terms) => {
+				if let Some(argument) = sigmoid_argument(&terms) {
+					return Sigmoid(Box::new(argument.clone()));
+				}
+				let mut terms = terms.into_iter();
+				let first = match terms.next() {
+					Some(first) => first,
+					None => return Quotient(vec!())
+				};
+				let mut operands = vec!(first);
+				operands.extend(terms.filter(|term| !is_constant(term, 1.0)));
+				if operands.len() == 1 {
+					operands.remove(0)
+				} else {
+					Quotient(operands)
+				}
+			},
+			Power(base, exponent) => {
+				if is_constant(&exponent, 1.0) {
+					*base
+				} else if is_constant(&exponent, 0.0) {
+					Constant(1.0)
+				} else {
+					Power(base, exponent)
+				}
+			},
+			Derivative { body, variable } => body.derivative(&variable).simplify(),
+			PowerInt(base, 1) => *base,
+			PowerInt(_, 0) => Constant(1.0),
+			Negation(term) => match *term {
+				Negation(inner) => *inner,
+				term => Negation(Box::new(term))
+			},
+			term => term
+		}
+	}
+
+	/// Expands products of sums by applying the distributive law.
+	///
+	/// Expansion proceeds bottom-up, so that no product in the result directly contains a sum; a product with several sum factors is expanded into the sum of every combination of their operands. Nested sums and products are flattened along the way, but nothing is otherwise simplified.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "(x + 1) * (x + 2)".parse().unwrap();
+	/// let expanded = t.expand();
+	/// assert_eq!(&format!("{}", expanded), "x * x + x * 2 + 1 * x + 1 * 2");
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 3.0);
+	/// assert!((t.evaluate(&values).unwrap() - 20.0).abs() < 0.00001);
+	/// assert!((expanded.evaluate(&values).unwrap() - 20.0).abs() < 0.00001);
+	/// ```
+	pub fn expand(&self) -> Term {
+		use Term::*;
+		let unwrap_sum = |term| match term { Sum(terms) => Ok(terms), term => Err(term) };
+		let unwrap_product = |term| match term { Product(terms) => Ok(terms), term => Err(term) };
+		match self.map_children(Term::expand) {
+			Sum(terms) => Sum(flatten(terms, unwrap_sum)),
+			Product(factors) => {
+				let mut combinations = vec!(vec!());
+				for factor in flatten(factors, unwrap_product) {
+					let operands = match factor {
+						Sum(terms) => terms,
+						factor => vec!(factor)
+					};
+					combinations = combinations.iter().flat_map(|combination: &Vec<Term>| operands.iter().map(move |operand| {
+						let mut combination = combination.clone();
+						combination.push(operand.clone());
+						combination
+					})).collect();
+				}
+				let mut products = combinations.into_iter().map(|factors| Product(flatten(factors, unwrap_product))).collect::<Vec<_>>();
+				if products.len() == 1 {
+					products.remove(0)
+				} else {
+					Sum(products)
+				}
+			},
+			term => term
+		}
+	}
+
+	/// Combines like terms in sums.
+	///
+	/// Each operand of a sum is split into a numeric coefficient (the product of its constant factors) and the remaining, non-constant part; operands whose non-constant parts are structurally equal are then combined by adding their coefficients, and all bare constants are folded into one. Combined terms appear where the first of their like terms did, and terms whose coefficients cancel out are dropped. This is applied bottom-up, but is otherwise conservative: only sums are rewritten, and terms are only alike if they are equal as written (so `x * y` and `y * x` are not combined).
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x + 2 * x + 3 + x * 4".parse().unwrap();
+	/// let collected = t.collect_like_terms();
+	/// assert_eq!(&format!("{}", collected), "7 * x + 3");
+	/// assert!(collected.count_operations() < t.count_operations());
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 1.5);
+	/// assert!((t.evaluate(&values).unwrap() - collected.evaluate(&values).unwrap()).abs() < 0.00001);
+	///
+	/// let u: Term = "sin(y) + 2 + -sin(y) + 1".parse().unwrap();
+	/// assert_eq!(u.collect_like_terms(), Term::Constant(3.0));
+	/// ```
+	pub fn collect_like_terms(&self) -> Term {
+		use Term::*;
+		match self.map_children(Term::collect_like_terms) {
+			Sum(terms) => {
+				// Like terms, keyed by their non-constant parts (`None` for bare constants), with their total coefficients.
+				let mut groups: Vec<(Option<Term>, f64)> = vec!();
+				for term in flatten(terms, |term| match term { Sum(terms) => Ok(terms), term => Err(term) }) {
+					let (coefficient, rest) = split_coefficient(term);
+					match groups.iter_mut().find(|group| group.0 == rest) {
+						Some(group) => group.1 += coefficient,
+						None => groups.push((rest, coefficient))
+					}
+				}
+				let operands = groups.into_iter().filter(|&(_, coefficient)| coefficient != 0.0).map(|(rest, coefficient)| with_coefficient(coefficient, rest)).collect();
+				collapse(operands, Sum, 0.0)
+			},
+			term => term
+		}
+	}
+
+	/// Splits a term into a numerator and a denominator, whose quotient is the term.
+	///
+	/// A quotient is split into its dividend and the product of its divisors (or its only divisor, if it has one), and quotients nested as dividends (as in `a / b / c`) are split in the same way, with their divisors joining the denominator. Any other term is its own numerator, with a denominator of one. Nothing is rearranged to find a fraction, so only quotients at the top of the term are considered.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let (a, b, c) = (Term::from('a'), Term::from('b'), Term::from('c'));
+	/// let t: Term = "a / b".parse().unwrap();
+	/// assert_eq!(t.as_fraction(), (a.clone(), b.clone()));
+	/// let t = Term::Quotient(vec!(a.clone(), b.clone(), c.clone()));
+	/// assert_eq!(t.as_fraction(), (a.clone(), b.clone() * c.clone()));
+	/// let t: Term = "a / b / c".parse().unwrap();
+	/// assert_eq!(t.as_fraction(), (a, b * c));
+	///
+	/// let t: Term = "x + 1".parse().unwrap();
+	/// assert_eq!(t.as_fraction(), (t.clone(), Term::one()));
+	/// ```
+	pub fn as_fraction(&self) -> (Term, Term) {
+		match *self {
+			Term::Quotient(ref terms) if !terms.is_empty() => {
+				let (numerator, mut denominator) = match terms[0] {
+					Term::Quotient(_) => {
+						let (numerator, denominator) = terms[0].as_fraction();
+						(numerator, Some(denominator))
+					},
+					ref dividend => (dividend.clone(), None)
+				};
+				for divisor in &terms[1..] {
+					denominator = Some(match denominator {
+						Some(denominator) => denominator * divisor.clone(),
+						None => divisor.clone()
+					});
+				}
+				(numerator, denominator.unwrap_or_else(Term::one))
+			},
+			_ => (self.clone(), Term::one())
+		}
+	}
+
+	/// Combines sums and differences of fractions into single fractions over a common denominator.
+	///
+	/// Each operand of a sum or difference is split into a numerator and denominator (see `Term::as_fraction`); the common denominator is the product of the denominators other than one, and each numerator is multiplied by the denominators of the other operands. This is applied bottom-up, and sums and differences without any fractions are left as they are. The common denominator is not necessarily the least one, and nothing is cancelled.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "1 / x + 1 / y".parse().unwrap();
+	/// let combined = t.combine_fractions();
+	/// assert_eq!(&format!("{}", combined), "(y + x) / (x * y)");
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// values.insert("y".to_string(), 4.0);
+	/// assert!((combined.evaluate(&values).unwrap() - 0.75).abs() < 0.00001);
+	///
+	/// let t: Term = "a / b - c".parse().unwrap();
+	/// assert_eq!(&format!("{}", t.combine_fractions()), "(a - c * b) / b");
+	/// ```
+	pub fn combine_fractions(&self) -> Term {
+		use Term::*;
+		let term = self.map_children(Term::combine_fractions);
+		let (terms, additive) = match term {
+			Sum(ref terms) => (terms, true),
+			Difference(ref terms) => (terms, false),
+			_ => return term
+		};
+		let fractions = terms.iter().map(Term::as_fraction).collect::<Vec<_>>();
+		if fractions.iter().all(|(_, denominator)| denominator.is_one()) {
+			return term;
+		}
+		let denominators = |except: Option<usize>| fractions.iter().enumerate()
+			.filter(|&(index, (_, denominator))| Some(index) != except && !denominator.is_one())
+			.map(|(_, (_, denominator))| denominator.clone())
+			.collect::<Vec<_>>();
+		let numerators = fractions.iter().enumerate().map(|(index, (numerator, _))| {
+			let factors = iter::once(numerator.clone()).filter(|numerator| !numerator.is_one()).chain(denominators(Some(index))).collect();
+			collapse(factors, Product, 1.0)
+		}).collect();
+		let numerator = if additive { Sum(numerators) } else { Difference(numerators) };
+		Quotient(vec!(numerator, collapse(denominators(None), Product, 1.0)))
+	}
+
+	/// Clears the denominators of the numeric coefficients of a sum, returning the scaled term and the factor by which it was multiplied.
+	///
+	/// The term is first simplified (see `Term::simplify`), and each of its operands (if it is a sum or difference; otherwise, the whole term) is split into a numeric coefficient and the rest. Constant divisors (as in `x / 2`) contribute to the coefficient, unless they are zero or not finite, in which case the operand is left as it is. Coefficients which are (to within rounding error) fractions with denominators of at most a million are recognized as such, and every operand is multiplied by the least common multiple of their denominators, so that those coefficients become integers. Other coefficients (such as multiples of `π` which have been folded) are merely scaled along with them. Dividing the result by the factor gives a term with the same value as the original.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x / 2 + 1 / 3".parse().unwrap();
+	/// let (cleared, factor) = t.clear_denominators();
+	/// assert_eq!(&format!("{}", cleared), "3 * x + 2");
+	/// assert_eq!(factor, 6.0);
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 5.0);
+	/// let restored = cleared / Term::Constant(factor);
+	/// assert!((restored.evaluate(&values).unwrap() - t.evaluate(&values).unwrap()).abs() < 0.00001);
+	///
+	/// let t: Term = "0.25 * x^2 - 1.5 * x * y".parse().unwrap();
+	/// let (cleared, factor) = t.clear_denominators();
+	/// assert_eq!(&format!("{}", cleared), "x^2 + -6 * x * y");
+	/// assert_eq!(factor, 4.0);
+	///
+	/// let t: Term = "x + 2".parse().unwrap();
+	/// assert_eq!(t.clear_denominators(), (t.clone(), 1.0));
+	///
+	/// let t: Term = "x / 0".parse().unwrap();
+	/// assert_eq!(t.clear_denominators(), (t.clone(), 1.0));
+	/// let (cleared, factor) = "x / 0 + y / 2".parse::<Term>().unwrap().clear_denominators();
+	/// assert_eq!((format!("{}", cleared), factor), ("2 * x / 0 + y".to_string(), 2.0));
+	/// ```
+	pub fn clear_denominators(&self) -> (Term, f64) {
+		use Term::*;
+		let operands = match self.simplify() {
+			Sum(terms) => terms.into_iter().map(|term| (1.0, term)).collect(),
+			Difference(terms) => terms.into_iter().enumerate().map(|(index, term)| (if index == 0 { 1.0 } else { -1.0 }, term)).collect(),
+			term => vec!((1.0, term))
+		};
+		let operands = operands.into_iter().map(|(sign, term)| {
+			let (numerator, divisor) = term.as_fraction();
+			let (coefficient, rest) = match divisor.as_constant() {
+				// Division by zero (or infinity) is left for evaluation to report.
+				Some(divisor) if divisor == 0.0 || !divisor.is_finite() => (1.0, Some(term)),
+				Some(divisor) => {
+					let (coefficient, rest) = split_coefficient(numerator);
+					(coefficient / divisor, rest)
+				},
+				None => split_coefficient(term)
+			};
+			(sign * coefficient, rest)
+		}).collect::<Vec<_>>();
+		let factor = operands.iter().filter_map(|&(coefficient, _)| denominator(coefficient)).fold(1.0, |factor, denominator| factor / gcd(factor, denominator) * denominator);
+		let operands = operands.into_iter().filter(|&(coefficient, _)| coefficient != 0.0).map(|(coefficient, rest)| {
+			let scaled = coefficient * factor;
+			let scaled = if denominator(coefficient).is_some() { scaled.round() } else { scaled };
+			with_coefficient(scaled, rest)
+		}).collect();
+		(collapse(operands, Sum, 0.0), factor)
+	}
+
+	/// Rewrites a term in a canonical form, so that terms differing only in the order of their sums and products become structurally equal.
+	///
+	/// The term is first simplified (see `Term::simplify`), which folds constants and flattens nested sums and products; the operands of every sum and product are then sorted. Operands are ordered by kind (constants, then variables, then `Term::Pi` and `Term::E`, then compound terms), then by value (constants numerically and variables by their own ordering), and then by their subterms.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a: Term = "x + y".parse().unwrap();
+	/// let b: Term = "y + x".parse().unwrap();
+	/// assert_ne!(a, b);
+	/// assert_eq!(a.canonicalize(), b.canonicalize());
+	///
+	/// let a: Term = "2 * (y * x) + sin(z) * 3 + 1".parse().unwrap();
+	/// let b: Term = "3 * sin(z) + (x * 2 * y + 1)".parse().unwrap();
+	/// assert_eq!(a.canonicalize(), b.canonicalize());
+	/// assert_eq!(&format!("{}", a.canonicalize()), "1 + 2 * x * y + 3 * sin(z)");
+	/// ```
+	pub fn canonicalize(&self) -> Term {
+		self.simplify().sort_operands()
+	}
+
+	/// Whether two terms are equivalent, in that they have the same canonical form (see `Term::canonicalize`).
+	///
+	/// This check is sound but incomplete: equivalent terms always have the same value, but many identities (such as `x * (y + z) = x * y + x * z` or `sin(x)^2 + cos(x)^2 = 1`) are beyond it, and terms related by them are reported as not equivalent. Only reorderings of sums and products, along with the rewrites performed by `Term::simplify`, are recognized.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let x = Term::from('x');
+	/// let y = Term::from('y');
+	/// assert!((x.clone() + y.clone()).equivalent(&(y.clone() + x.clone())));
+	/// assert!(!(x.clone() + y.clone()).equivalent(&(x.clone() * y.clone())));
+	///
+	/// let a: Term = "2 + x * 3 + 1".parse().unwrap();
+	/// let b: Term = "3 * x + 3".parse().unwrap();
+	/// assert!(a.equivalent(&b));
+	/// ```
+	pub fn equivalent(&self, other: &Term) -> bool {
+		self.canonicalize() == other.canonicalize()
+	}
+
+	/// Sorts the operands of every sum and product in a term (see `Term::canonicalize`).
+	fn sort_operands(&self) -> Term {
+		use Term::*;
+		match self.map_children(Term::sort_operands) {
+			Sum(mut terms) => {
+				terms.sort_by(compare);
+				Sum(terms)
+			},
+			Product(mut terms) => {
+				terms.sort_by(compare);
+				Product(terms)
+			},
+			term => term
+		}
+	}
+}
+
+/// The exact value of a sine, cosine, or tangent of a special angle (a multiple of `π / 6` or `π / 4`), if `term` is one.
+fn exact_trigonometric_value(term: &Term) -> Option<f64> {
+	let (argument, offset) = match *term {
+		Term::Sine(ref argument) | Term::Tangent(ref argument) => (argument, 0),
+		Term::Cosine(ref argument) => (argument, 6),
+		_ => return None
+	};
+	// The angle, in twelfths of π (that is, multiples of 15°).
+	let twelfths = pi_multiple(argument)? * 12.0;
+	if (twelfths - twelfths.round()).abs() > 1e-9 {
+		return None;
+	}
+	let twelfths = twelfths.round() as i64;
+	let value = exact_sine(twelfths + offset)?;
+	match *term {
+		Term::Tangent(_) => match exact_sine(twelfths + 6)? {
+			0.0 => None,
+			cosine => Some(value / cosine)
+		},
+		_ => Some(value)
+	}
+}
+
+/// The argument `x` of a quotient written as `1 / (1 + exp(-x))`, if `terms` are its operands.
+fn sigmoid_argument(terms: &[Term]) -> Option<&Term> {
+	if terms.len() != 2 || !is_constant(&terms[0], 1.0) {
+		return None;
+	}
+	let addends = match terms[1] {
+		Term::Sum(ref addends) if addends.len() == 2 => addends,
+		_ => return None
+	};
+	let exponential = match (&addends[0], &addends[1]) {
+		(one, exponential) | (exponential, one) if is_constant(one, 1.0) => exponential,
+		_ => return None
+	};
+	match *exponential {
+		Term::Exp(ref exponent) => match **exponent {
+			Term::Negation(ref argument) => Some(argument),
+			_ => None
+		},
+		_ => None
+	}
+}
+
+/// The exact sine of `twelfths * π / 12`, if it is a special angle.
+fn exact_sine(twelfths: i64) -> Option<f64> {
+	let twelfths = twelfths.rem_euclid(24);
+	let (sign, twelfths) = if twelfths >= 12 { (-1.0, twelfths - 12) } else { (1.0, twelfths) };
+	let twelfths = if twelfths > 6 { 12 - twelfths } else { twelfths };
+	match twelfths {
+		0 => Some(0.0),
+		2 => Some(sign * 0.5),
+		3 => Some(sign * consts::FRAC_1_SQRT_2),
+		4 => Some(sign * 3f64.sqrt() / 2.0),
+		6 => Some(sign),
+		_ => None
+	}
+}
+
+/// The multiple of π which `term` is written as, if it is a constant multiple (or fraction) of `Term::Pi`.
+fn pi_multiple(term: &Term) -> Option<f64> {
+	match *term {
+		Term::Pi => Some(1.0),
+		Term::Negation(ref term) => pi_multiple(term).map(|multiple| -multiple),
+		Term::Product(ref factors) => {
+			let mut coefficient = 1.0;
+			let mut multiple = None;
+			for factor in factors {
+				match *factor {
+					Term::Constant(value) => coefficient *= value,
+					ref factor if multiple.is_none() => multiple = Some(pi_multiple(factor)?),
+					_ => return None
+				}
+			}
+			multiple.map(|multiple| coefficient * multiple)
+		},
+		Term::Quotient(ref terms) if terms.len() == 2 => match terms[1] {
+			Term::Constant(divisor) if divisor != 0.0 => pi_multiple(&terms[0]).map(|multiple| multiple / divisor),
+			_ => None
+		},
+		_ => None
+	}
+}
+
+/// The kind of a term, for the purposes of ordering operands (see `compare`).
+fn kind(term: &Term) -> (u8, &'static str) {
+	use Term::*;
+	match *term {
+		Constant(_) => (0, ""),
+		Variable(_) => (1, ""),
+		Pi => (2, "pi"),
+		E => (2, "e"),
+		Sum(_) => (3, "+"),
+		Difference(_) => (3, "-"),
+		Product(_) => (3, "*"),
+		Quotient(_) => (3, "/"),
+		Power(_, _) => (3, "^"),
+		Negation(_) => (3, "neg"),
+		ref term => (4, term.function_name().unwrap_or(""))
+	}
+}
+
+/// A total order on terms, used to sort the operands of sums and products (see `Term::canonicalize`).
+///
+/// Terms are compared by kind, then by value, then by their subterms (lexicographically), and finally by their displayed forms, which distinguishes any terms not already told apart (such as indexed terms with different indices).
+fn compare(a: &Term, b: &Term) -> Ordering {
+	kind(a).cmp(&kind(b))
+		.then_with(|| match (a, b) {
+			(Term::Constant(a), Term::Constant(b)) => a.total_cmp(b),
+			(Term::Variable(a), Term::Variable(b)) => a.cmp(b),
+			_ => Ordering::Equal
+		})
+		.then_with(|| {
+			let (a, b) = (a.children(), b.children());
+			a.iter().zip(&b)
+				.map(|(a, b)| compare(a, b))
+				.find(|&ordering| ordering != Ordering::Equal)
+				.unwrap_or_else(|| a.len().cmp(&b.len()))
+		})
+		.then_with(|| a.to_string().cmp(&b.to_string()))
+}
+
+/// Whether `term` is exactly the constant `value`.
+fn is_constant(term: &Term, value: f64) -> bool {
+	term.as_constant() == Some(value)
+}
+
+/// Splits a term into its numeric coefficient and its non-constant part (if it has one).
+fn split_coefficient(term: Term) -> (f64, Option<Term>) {
+	match term {
+		Term::Constant(value) => (value, None),
+		Term::Negation(term) => {
+			let (coefficient, rest) = split_coefficient(*term);
+			(-coefficient, rest)
+		},
+		Term::Product(factors) => {
+			let mut coefficient = 1.0;
+			let mut rest = vec!();
+			for factor in factors {
+				match factor {
+					Term::Constant(value) => coefficient *= value,
+					factor => rest.push(factor)
+				}
+			}
+			(coefficient, match rest.len() {
+				0 => None,
+				1 => Some(rest.remove(0)),
+				_ => Some(Term::Product(rest))
+			})
+		},
+		term => (1.0, Some(term))
+	}
+}
+
+/// Rebuilds a term from a numeric coefficient and a non-constant part (if it has one), as split by `split_coefficient`.
+fn with_coefficient(coefficient: f64, rest: Option<Term>) -> Term {
+	match (rest, coefficient) {
+		(None, coefficient) => Term::Constant(coefficient),
+		(Some(term), 1.0) => term,
+		(Some(Term::Product(factors)), coefficient) => Term::Product(iter::once(Term::Constant(coefficient)).chain(factors).collect()),
+		(Some(term), coefficient) => Term::Product(vec!(Term::Constant(coefficient), term))
+	}
+}
+
+/// The largest denominator of a fraction recognized by `denominator`.
+const MAX_DENOMINATOR: f64 = 1e6;
+
+/// The denominator of `value` as a fraction in lowest terms, if it is one (to within rounding error) with a denominator of at most `MAX_DENOMINATOR`.
+///
+/// The fraction is found as a convergent of the continued fraction expansion of `value`.
+fn denominator(value: f64) -> Option<f64> {
+	if !value.is_finite() {
+		return None;
+	}
+	let magnitude = value.abs();
+	let tolerance = 4.0 * f64::EPSILON * magnitude.max(1.0);
+	// The previous two convergents, as (numerator, denominator) pairs.
+	let (mut previous, mut current) = ((0.0, 1.0), (1.0, 0.0));
+	let mut remainder = magnitude;
+	// The denominators grow at least as quickly as the Fibonacci numbers, so this is plenty of terms.
+	for _ in 0..64 {
+		let whole = remainder.floor();
+		let next = (whole * current.0 + previous.0, whole * current.1 + previous.1);
+		if next.1 > MAX_DENOMINATOR {
+			return None;
+		}
+		if (next.0 / next.1 - magnitude).abs() <= tolerance {
+			return Some(next.1);
+		}
+		previous = current;
+		current = next;
+		remainder = 1.0 / (remainder - whole);
+	}
+	None
+}
+
+/// The greatest common divisor of two (positive, integral) numbers.
+fn gcd(a: f64, b: f64) -> f64 {
+	if b == 0.0 { a } else { gcd(b, a % b) }
+}
+
+/// Splices the operands of nested terms of the same kind (as identified by `unwrap`) into a single operand list.
+fn flatten<F: Fn(Term) -> Result<Vec<Term>, Term>>(terms: Vec<Term>, unwrap: F) -> Vec<Term> {
+	let mut flattened = vec!();
+	for term in terms {
+		match unwrap(term) {
+			Ok(inner) => flattened.extend(inner),
+			Err(term) => flattened.push(term)
+		}
+	}
+	flattened
+}
+
+/// Rebuilds a sum or product from its remaining operands, unwrapping single operands and replacing an empty operand list with `identity`.
+fn collapse<F: Fn(Vec<Term>) -> Term>(mut operands: Vec<Term>, build: F, identity: f64) -> Term {
+	match operands.len() {
+		0 => Term::Constant(identity),
+		1 => operands.remove(0),
+		_ => build(operands)
+	}
+}
@@ -0,0 +1,188 @@
+use error::EvalError;
+use std::collections::HashMap;
+use std::f32::consts;
+use term::{AngleMode, Term, DIVISION_EPSILON, POLE_EPSILON};
+
+type SingleValues = HashMap<String, f32>;
+
+/// The factor by which `f32` is less precise than `f64`, by which the `f64` epsilons are scaled for single-precision evaluation.
+const PRECISION_RATIO: f64 = f32::EPSILON as f64 / f64::EPSILON;
+
+/// How close to zero a divisor must be to be treated as zero in single precision (see `DIVISION_EPSILON`).
+const SINGLE_DIVISION_EPSILON: f32 = (DIVISION_EPSILON * PRECISION_RATIO) as f32;
+
+/// How close to zero the sine or cosine of an angle must be to be treated as a pole in single precision (see `POLE_EPSILON`).
+const SINGLE_POLE_EPSILON: f32 = (POLE_EPSILON * PRECISION_RATIO) as f32;
+
+/// Ensures that `value` is not (approximately) zero, since it is about to be divided by.
+fn nonzero(value: f32, epsilon: f32) -> Result<f32, EvalError> {
+	if value.abs() < epsilon {
+		Err(EvalError::DivisionByZero)
+	} else {
+		Ok(value)
+	}
+}
+
+/// Reports `value` as being outside the domain of `function`.
+fn domain_error(function: &str, value: f32) -> EvalError {
+	EvalError::DomainError { function: function.to_string(), value: f64::from(value) }
+}
+
+impl Term {
+	/// Evaluates a term in single precision, given the (`f32`) values of its variables.
+	///
+	/// Every operation is carried out in `f32` arithmetic, with constants rounded to `f32` as they are encountered; errors are reported as by `Term::evaluate`, except that divisors (and the sines and cosines of the reciprocal trigonometric functions) are checked against tolerances scaled up by the lower precision of `f32`. The only exception is the factorial of a non-integer, which is computed in double precision (through the gamma function) and then rounded. Angles are measured in radians.
+	///
+	/// This method is only available with the `f32` feature.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "sin(x) * 2 + 1".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 0.5f32);
+	/// assert!((t.evaluate_f32(&values).unwrap() - (0.5f32.sin() * 2.0 + 1.0)).abs() < 0.0001);
+	///
+	/// let q: Term = "1 / (x - 0.5)".parse().unwrap();
+	/// assert_eq!(q.evaluate_f32(&values), Err(EvalError::DivisionByZero));
+	/// ```
+	pub fn evaluate_f32(&self, values: &SingleValues) -> Result<f32, EvalError> {
+		self.evaluate_postorder(|term, operands| term.apply_f32(&operands, values))
+	}
+
+	/// Computes the single-precision value of a term given the values of its children, in order (see `Term::apply`).
+	fn apply_f32(&self, operands: &[f32], values: &SingleValues) -> Result<f32, EvalError> {
+		use Term::*;
+		if let Some((index, from, to, body)) = self.indexed() {
+			let summation = matches!(*self, Summation { .. });
+			let function = format!("{} bound", if summation { "summation" } else { "product" });
+			let bound = |term: &Term| -> Result<f32, EvalError> {
+				let value = term.evaluate_f32(values)?;
+				if value.fract() != 0.0 {
+					return Err(domain_error(&function, value));
+				}
+				Ok(value)
+			};
+			let (mut value, to) = (bound(from)?, bound(to)?);
+			let mut bindings = values.clone();
+			let mut result = if summation { 0.0 } else { 1.0 };
+			while value <= to {
+				bindings.insert(index.to_string(), value);
+				let term = body.evaluate_f32(&bindings)?;
+				result = if summation { result + term } else { result * term };
+				value += 1.0;
+			}
+			return Ok(result);
+		}
+		let unary = || operands[0];
+		match *self {
+			Constant(value) => Ok(value as f32),
+			Variable(ref variable) => values.get(&variable.symbol).cloned().ok_or_else(|| EvalError::UndefinedVariable(variable.symbol.clone())),
+			Pi => Ok(consts::PI),
+			E => Ok(consts::E),
+			Sum(_) => Ok(operands.iter().sum()),
+			Difference(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("difference".to_string()))?;
+				Ok(rest.iter().fold(*first, |difference, value| difference - value))
+			},
+			Product(_) => Ok(operands.iter().product()),
+			Quotient(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("quotient".to_string()))?;
+				rest.iter().try_fold(*first, |quotient, &divisor| Ok(quotient / nonzero(divisor, SINGLE_DIVISION_EPSILON)?))
+			},
+			Sine(_) => Ok(unary().sin()),
+			Cosine(_) => Ok(unary().cos()),
+			Tangent(_) => Ok(unary().tan()),
+			ArcSine(_) => Ok(unary().asin()),
+			ArcCosine(_) => Ok(unary().acos()),
+			ArcTangent(_) => Ok(unary().atan()),
+			Atan2 { .. } => Ok(operands[0].atan2(operands[1])),
+			Power(_, _) => Ok(operands[0].powf(operands[1])),
+			PowerInt(_, exponent) if exponent < 0 => Ok(nonzero(unary(), SINGLE_DIVISION_EPSILON)?.powi(exponent)),
+			PowerInt(_, exponent) => Ok(unary().powi(exponent)),
+			Exp(_) => Ok(unary().exp()),
+			ToRadians(_) => Ok(unary().to_radians()),
+			ToDegrees(_) => Ok(unary().to_degrees()),
+			Sigmoid(_) => match unary() {
+				value if value >= 0.0 => Ok(1.0 / (1.0 + (-value).exp())),
+				value => Ok(value.exp() / (1.0 + value.exp()))
+			},
+			NaturalLog(_) => match unary() {
+				value if value <= 0.0 => Err(domain_error("ln", value)),
+				value => Ok(value.ln())
+			},
+			Logarithm(_, _) => {
+				let (value, base) = (operands[0], operands[1]);
+				if value <= 0.0 {
+					return Err(domain_error("log", value));
+				}
+				if base <= 0.0 || base == 1.0 {
+					return Err(domain_error("log base", base));
+				}
+				Ok(value.log(base))
+			},
+			SquareRoot(_) => match unary() {
+				value if value < 0.0 => Err(domain_error("sqrt", value)),
+				value => Ok(value.sqrt())
+			},
+			NthRoot(_, _) => {
+				let (value, index) = (operands[0], operands[1]);
+				if index == 0.0 {
+					return Err(domain_error("root index", index));
+				}
+				if value >= 0.0 {
+					Ok(value.powf(1.0 / index))
+				} else if index.fract() == 0.0 && index % 2.0 != 0.0 {
+					Ok(-(-value).powf(1.0 / index))
+				} else {
+					Err(domain_error("root", value))
+				}
+			},
+			AbsoluteValue(_) => Ok(unary().abs()),
+			Negation(_) => Ok(-unary()),
+			Sinh(_) => Ok(unary().sinh()),
+			Cosh(_) => Ok(unary().cosh()),
+			Tanh(_) => Ok(unary().tanh()),
+			Secant(_) => Ok(1.0 / nonzero(unary().cos(), SINGLE_POLE_EPSILON)?),
+			Cosecant(_) => Ok(1.0 / nonzero(unary().sin(), SINGLE_POLE_EPSILON)?),
+			Cotangent(_) => Ok(unary().cos() / nonzero(unary().sin(), SINGLE_POLE_EPSILON)?),
+			Modulo(_, _) => Ok(operands[0].rem_euclid(nonzero(operands[1], SINGLE_DIVISION_EPSILON)?)),
+			Factorial(_) => Ok(self.apply(&[f64::from(unary())], None, AngleMode::Radians)? as f32),
+			Binomial { .. } => Ok(self.apply(&[f64::from(operands[0]), f64::from(operands[1])], None, AngleMode::Radians)? as f32),
+			Derivative { ref body, ref variable } => body.derivative(variable).evaluate_f32(values),
+			Piecewise(ref branches) => {
+				for (condition, term) in branches {
+					let (a, b) = condition.operands();
+					if condition.compare(a.evaluate_f32(values)?, b.evaluate_f32(values)?) {
+						return term.evaluate_f32(values);
+					}
+				}
+				Err(EvalError::NoMatchingBranch)
+			},
+			Floor(_) => Ok(unary().floor()),
+			Ceiling(_) => Ok(unary().ceil()),
+			Sign(_) => match unary() {
+				0.0 => Ok(0.0),
+				value => Ok(value.signum())
+			},
+			Round(_) => Ok(unary().round()),
+			Truncate(_) => Ok(unary().trunc()),
+			Fractional(_) => Ok(unary().fract()),
+			Clamp { .. } => {
+				let (value, min, max) = (operands[0], operands[1], operands[2]);
+				if min > max || min.is_nan() || max.is_nan() {
+					return Err(domain_error("clamp bounds", min));
+				}
+				Ok(value.clamp(min, max))
+			},
+			Maximum(_) | Minimum(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands(self.function_name().unwrap_or_default().to_string()))?;
+				let maximum = matches!(*self, Maximum(_));
+				Ok(rest.iter().fold(*first, |extreme, &value| if maximum { extreme.max(value) } else { extreme.min(value) }))
+			},
+			Summation { .. } | IndexedProduct { .. } => unreachable!()
+		}
+	}
+}
@@ -1,16 +1,135 @@
+use condition::Condition;
+use error::EvalError;
 use variable::Variable;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::f64::consts;
+use std::iter;
+use std::ptr;
+use std::mem;
 use std::ops::Add;
+use std::ops::AddAssign;
 use std::ops::Sub;
 use std::ops::Mul;
+use std::ops::MulAssign;
 use std::ops::Div;
+use std::ops::Neg;
 
-type VariableValues = HashMap<char, f64>;
+pub(crate) type VariableValues = HashMap<String, f64>;
+
+/// How close to zero a divisor must be to be treated as zero.
+pub(crate) const DIVISION_EPSILON: f64 = 0.00000000000000001;
+
+/// How close to zero the sine or cosine of an angle must be for the reciprocal trigonometric functions to treat it as a pole.
+pub(crate) const POLE_EPSILON: f64 = 1e-12;
+
+/// The largest integer whose factorial is finite as an `f64`.
+const MAXIMUM_FACTORIAL: f64 = 170.0;
+
+/// Coefficients of the Lanczos approximation to the gamma function (with `g = 7`).
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+	0.999_999_999_999_809_9,
+	676.520_368_121_885_1,
+	-1_259.139_216_722_402_8,
+	771.323_428_777_653_1,
+	-176.615_029_162_140_6,
+	12.507_343_278_686_905,
+	-0.138_571_095_265_720_12,
+	9.984_369_578_019_572e-6,
+	1.505_632_735_149_311_6e-7
+];
+
+/// The unit in which trigonometric functions take (and inverse trigonometric functions return) angles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AngleMode {
+	/// Angles are measured in radians (the default).
+	Radians,
+	/// Angles are measured in degrees.
+	Degrees
+}
+
+impl AngleMode {
+	/// Converts an angle in this unit to radians.
+	fn to_radians(self, angle: f64) -> f64 {
+		match self {
+			AngleMode::Radians => angle,
+			AngleMode::Degrees => angle.to_radians()
+		}
+	}
+	/// Converts an angle in radians to this unit.
+	fn convert_radians(self, angle: f64) -> f64 {
+		match self {
+			AngleMode::Radians => angle,
+			AngleMode::Degrees => angle.to_degrees()
+		}
+	}
+}
+
+/// Approximates the gamma function, using the reflection formula to handle arguments below one half.
+fn gamma(x: f64) -> f64 {
+	use std::f64::consts::PI;
+	if x < 0.5 {
+		return PI / ((PI * x).sin() * gamma(1.0 - x));
+	}
+	let x = x - 1.0;
+	let t = x + 7.5;
+	let series = LANCZOS_COEFFICIENTS[1..].iter().enumerate().fold(LANCZOS_COEFFICIENTS[0], |sum, (index, coefficient)| {
+		sum + coefficient / (x + index as f64 + 1.0)
+	});
+	(2.0 * PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * series
+}
+
+/// Evaluates `body` once for each integer from `from` to `to` (inclusive), with `index` bound to that integer.
+///
+/// The bounds must evaluate to integers; `function` names the indexed operation in the resulting error if they do not. Divisors are treated as zero if they are within `epsilon` of it (see `Term::eval_with_epsilon`).
+fn evaluate_indexed(function: &str, (index, from, to, body): (&str, &Term, &Term, &Term), values: Option<&VariableValues>, mode: AngleMode, epsilon: f64) -> Result<Vec<f64>, EvalError> {
+	let bound = |term: &Term| -> Result<f64, EvalError> {
+		let value = term.eval_with_epsilon(values, mode, epsilon)?;
+		if value.fract() != 0.0 {
+			return Err(EvalError::DomainError { function: format!("{} bound", function), value });
+		}
+		Ok(value)
+	};
+	let (from, to) = (bound(from)?, bound(to)?);
+	let mut bindings = values.cloned().unwrap_or_default();
+	let mut results = vec!();
+	let mut value = from;
+	while value <= to {
+		bindings.insert(index.to_string(), value);
+		results.push(body.eval_with_epsilon(Some(&bindings), mode, epsilon)?);
+		value += 1.0;
+	}
+	Ok(results)
+}
+
+/// A unit of work for the (iterative) evaluator; see `Term::eval`.
+enum Step<'a> {
+	/// Evaluate a term, scheduling its children first if it has any.
+	Visit(&'a Term),
+	/// Apply a term to the values of its children, which are the given number of values at the top of the result stack.
+	Apply(&'a Term, usize),
+	/// Ensure that the value at the top of the result stack is a valid divisor.
+	CheckDivisor
+}
 
 /// Terms are basic mathematical building blocks, from which are formed expressions and more complex entities.
 ///
 /// The `Term` data type (currently) represents basic polynomial components, which can be assigned a numeric value with `Term::evaluate`/`Term::reduce`.
-#[derive(Clone)]
+///
+/// Terms compare *structurally*: two terms are equal only if they are the same variant with equal (in the exact, `f64` sense) constants, equal variables, and pairwise-equal subterms in the same order. In particular, `a + b` is not considered equal to `b + a`.
+///
+/// # Examples
+/// ```
+/// use cassie::{Term, Variable};
+///
+/// let x = Term::Variable(Variable::named('x'));
+/// let y = Term::Variable(Variable::named('y'));
+/// assert_eq!(&x + &y, Term::Sum(vec!(x.clone(), y.clone())));
+/// assert!(&x + &y != &y + &x);
+/// assert!(&x + &y != &x * &y);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Term {
 	/// Represents a term which simply a variable, one of the two foundational term types.
 	///
@@ -22,7 +141,7 @@ pub enum Term {
 	/// use std::collections::HashMap;
 	///
 	/// let mut bindings = HashMap::new();
-	/// bindings.insert('φ', 68.0);
+	/// bindings.insert("φ".to_string(), 68.0);
 	///
 	/// let f = Variable::named('φ');
 	/// let f = Term::Variable(f);
@@ -43,7 +162,7 @@ pub enum Term {
 	Constant(f64),
 	/// Represents a sum of multiple terms.
 	///
-	/// To calculate the value of this term, the components are evaluated iteratively from the first to last index.
+	/// To calculate the value of this term, the components are evaluated iteratively from the first to last index. An empty sum has the value zero.
 	///
 	/// #Examples
 	/// ```
@@ -58,15 +177,17 @@ pub enum Term {
 	/// let d = Term::Constant(27.0);
 	/// let z = c + d; // Preferred
 	/// assert!(z.reduce().unwrap() - 39.0 < 0.00001);
+	///
+	/// assert_eq!(Term::Sum(vec!()).reduce(), Ok(0.0));
 	/// ```
 	Sum(Vec<Term>),
 	/// Represents a difference of terms.
 	///
-	/// The first term is used as-is; all others have their signs inverted and are added to the first term in ascending order of index.
+	/// The first term is used as-is; all others have their signs inverted and are added to the first term in ascending order of index. Since there is no first term to subtract from, an empty difference produces `EvalError::NoOperands`. For the same reason, an empty difference has no infix (or postfix) form, and is displayed as nothing at all.
 	///
 	/// #Examples
 	/// ```
-	/// use cassie::Term;
+	/// use cassie::{EvalError, Term};
 	///
 	/// let a = Term::Constant(24.0);
 	/// let b = Term::Constant(72.0);
@@ -77,11 +198,14 @@ pub enum Term {
 	/// let d = Term::Constant(27.0);
 	/// let z = c - d; // Preferred
 	/// assert!((z.reduce().unwrap() + 15.0).abs() < 0.00001);
+	///
+	/// assert_eq!(Term::Difference(vec!()).reduce(), Err(EvalError::NoOperands("difference".to_string())));
+	/// assert_eq!(&format!("{}", Term::Difference(vec!())), "");
 	/// ```
 	Difference(Vec<Term>),
 	/// Represents a product of terms.
 	///
-	/// All terms are multiplied together after evaluation, with evaluation proceeding in ascending index order.
+	/// All terms are multiplied together after evaluation, with evaluation proceeding in ascending index order. An empty product has the value one.
 	///
 	/// #Examples
 	/// ```
@@ -100,7 +224,7 @@ pub enum Term {
 	Product(Vec<Term>),
 	/// Represents a quotient of terms.
 	///
-	/// The first term is evaluated, then divided by each following term in order of ascending index (each term is used immediately after evaluation). Fairly aggressive sanity checks are performed to prevent division by zero; if this continues to pester you, consider multiplying by the inverse instead.
+	/// The first term is evaluated, then divided by each following term in order of ascending index (each term is used immediately after evaluation). Fairly aggressive sanity checks are performed to prevent division by zero; if this continues to pester you, consider multiplying by the inverse instead. Since there is no dividend, an empty quotient produces `EvalError::NoOperands`. For the same reason, an empty quotient has no infix (or postfix) form, and is displayed as nothing at all.
 	///
 	/// This variant should be considered more or less unstable; it is only due to typing constraints that simplification is implemented for more than two subterms. **Consider using `Term::Product` instead, if possible.**
 	///
@@ -117,6 +241,14 @@ pub enum Term {
 	/// let d = Term::Constant(-1.0);
 	/// let z = c / d; // Preferred
 	/// assert!((z.reduce().unwrap() + 3.0).abs() < 0.00001);
+	///
+	/// // The first term is only used as the dividend.
+	/// let q = Term::Quotient(vec!(Term::Constant(20.0), Term::Constant(4.0)));
+	/// assert!((q.reduce().unwrap() - 5.0).abs() < 0.00001);
+	/// let q = Term::Quotient(vec!(Term::Constant(20.0), Term::Constant(4.0), Term::Constant(2.0)));
+	/// assert!((q.reduce().unwrap() - 2.5).abs() < 0.00001);
+	///
+	/// assert!(Term::Quotient(vec!()).reduce().is_err());
 	/// ```
 	Quotient(Vec<Term>), // Look into limiting vector sizes to avoid confusion (due to bad input).
 	/// Represents the sine function.
@@ -154,179 +286,1865 @@ pub enum Term {
 	/// The associated term is evaluated and passed to an inverse tangent function to obtain a result.
 	///
 	/// Like any self-respecting trigonometric function, this performs operations "in radians."
-	ArcTangent(Box<Term>) // TODO: Verify that this is what we want (this uses heap memory).
-}
-
-impl Term {
-	/// Evaluates a term to its numerical value.
+	ArcTangent(Box<Term>), // TODO: Verify that this is what we want (this uses heap memory).
+	/// Represents exponentiation.
 	///
-	/// # Examples
+	/// The first term is the base and the second is the exponent; both are evaluated and combined with `f64::powf`.
+	///
+	/// Following `f64` semantics, `0^0` evaluates to `1`.
+	///
+	/// #Examples
 	/// ```
 	/// use cassie::{Term, Variable};
 	/// use std::collections::HashMap;
 	///
-	/// let x: Variable = "x".parse().unwrap();
-	/// let x = Term::Variable(x);
-	/// let c = Term::Constant(100.0);
-	/// let s = x + c;
+	/// let mut bindings = HashMap::new();
+	/// bindings.insert("x".to_string(), 3.0);
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let y = Term::Power(Box::new(x), Box::new(Term::Constant(2.0)));
+	/// assert!((y.evaluate(&bindings).unwrap() - 9.0).abs() < 0.00001);
+	///
+	/// let z = Term::Power(Box::new(Term::Constant(0.0)), Box::new(Term::Constant(0.0)));
+	/// assert!((z.reduce().unwrap() - 1.0).abs() < 0.00001);
+	/// ```
+	Power(Box<Term>, Box<Term>),
+	/// Represents the natural exponential function.
+	///
+	/// The associated term is evaluated and used as the exponent of *e*.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::f64::consts::E;
+	///
+	/// let a = Term::Exp(Box::new(Term::Constant(0.0)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let b = Term::Exp(Box::new(Term::Constant(1.0)));
+	/// assert!((b.reduce().unwrap() - E).abs() < 1e-10);
+	/// ```
+	Exp(Box<Term>),
+	/// Represents the natural logarithm.
+	///
+	/// The associated term is evaluated and its natural logarithm is taken. Non-positive arguments are rejected rather than producing `NaN`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::f64::consts::E;
+	///
+	/// let a = Term::NaturalLog(Box::new(Term::Constant(E)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let b = Term::NaturalLog(Box::new(Term::Constant(0.0)));
+	/// assert!(b.reduce().is_err());
+	/// ```
+	NaturalLog(Box<Term>),
+	/// Represents a logarithm of arbitrary base.
+	///
+	/// The first term is the argument and the second is the base. Non-positive arguments are rejected, as are bases which are non-positive or equal to one.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Logarithm(Box::new(Term::Constant(8.0)), Box::new(Term::Constant(2.0)));
+	/// assert!((a.reduce().unwrap() - 3.0).abs() < 0.00001);
+	///
+	/// let b = Term::Logarithm(Box::new(Term::Constant(8.0)), Box::new(Term::Constant(1.0)));
+	/// assert!(b.reduce().is_err());
+	/// ```
+	Logarithm(Box<Term>, Box<Term>),
+	/// Represents the square root function.
+	///
+	/// The associated term is evaluated and its (principal) square root is taken. In keeping with the sanity checks performed by `Term::Quotient`, negative values are rejected rather than producing `NaN`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::SquareRoot(Box::new(Term::Constant(9.0)));
+	/// assert!((a.reduce().unwrap() - 3.0).abs() < 0.00001);
+	///
+	/// let b = Term::SquareRoot(Box::new(Term::Constant(-4.0)));
+	/// assert!(b.reduce().is_err());
+	/// ```
+	SquareRoot(Box<Term>),
+	/// Represents an arbitrary root.
+	///
+	/// The first term is the radicand and the second is the index *n*. Non-negative radicands are evaluated as `value.powf(1.0 / n)`. Negative radicands are only accepted when *n* is an odd integer, in which case the real root is returned (so the cube root of `-8` is `-2`); otherwise, as with `Term::SquareRoot`, an error is returned. An index of zero is also rejected.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::NthRoot(Box::new(Term::Constant(-8.0)), Box::new(Term::Constant(3.0)));
+	/// assert!((a.reduce().unwrap() + 2.0).abs() < 0.00001);
+	///
+	/// let b = Term::NthRoot(Box::new(Term::Constant(16.0)), Box::new(Term::Constant(4.0)));
+	/// assert!((b.reduce().unwrap() - 2.0).abs() < 0.00001);
+	///
+	/// let c = Term::NthRoot(Box::new(Term::Constant(-16.0)), Box::new(Term::Constant(4.0)));
+	/// assert!(c.reduce().is_err());
+	/// ```
+	NthRoot(Box<Term>, Box<Term>),
+	/// Represents the absolute value function.
+	///
+	/// The associated term is evaluated and its magnitude is returned.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::AbsoluteValue(Box::new(Term::Constant(-5.0)));
+	/// assert!((a.reduce().unwrap() - 5.0).abs() < 0.00001);
+	/// ```
+	AbsoluteValue(Box<Term>),
+	/// Represents the negation of a term.
+	///
+	/// The associated term is evaluated and its sign is inverted.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Negation(Box::new(Term::Constant(5.0))); // Notice that this is very ugly; see below
+	/// assert!((a.reduce().unwrap() + 5.0).abs() < 0.00001);
+	///
+	/// let b = -Term::Constant(-3.0); // Preferred
+	/// assert!((b.reduce().unwrap() - 3.0).abs() < 0.00001);
+	/// ```
+	Negation(Box<Term>),
+	/// Represents the hyperbolic sine function.
+	///
+	/// The associated term is evaluated and passed to a hyperbolic sine function to obtain a result.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Sinh(Box::new(Term::Constant(0.0)));
+	/// assert!(a.reduce().unwrap().abs() < 0.00001);
+	/// ```
+	Sinh(Box<Term>),
+	/// Represents the hyperbolic cosine function.
+	///
+	/// The associated term is evaluated and passed to a hyperbolic cosine function to obtain a result.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Cosh(Box::new(Term::Constant(0.0)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	/// ```
+	Cosh(Box<Term>),
+	/// Represents the hyperbolic tangent function.
+	///
+	/// The associated term is evaluated and passed to a hyperbolic tangent function to obtain a result.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Tanh(Box::new(Term::Constant(0.0)));
+	/// assert!(a.reduce().unwrap().abs() < 0.00001);
+	/// let b = Term::Tanh(Box::new(Term::Constant(100.0)));
+	/// assert!((b.reduce().unwrap() - 1.0).abs() < 0.00001);
+	/// ```
+	Tanh(Box<Term>),
+	/// Represents the secant function.
+	///
+	/// The associated term is evaluated and the reciprocal of its cosine is taken. As with `Term::Quotient`, arguments at which the cosine is (approximately) zero are rejected.
+	///
+	/// Like any self-respecting trigonometric function, this performs operations "in radians."
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::f64::consts::PI;
+	///
+	/// let a = Term::Secant(Box::new(Term::Constant(0.0)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let b = Term::Secant(Box::new(Term::Constant(PI / 2.0)));
+	/// assert_eq!(b.reduce(), Err(EvalError::DivisionByZero));
+	/// ```
+	Secant(Box<Term>),
+	/// Represents the cosecant function.
+	///
+	/// The associated term is evaluated and the reciprocal of its sine is taken. As with `Term::Quotient`, arguments at which the sine is (approximately) zero are rejected.
+	///
+	/// Like any self-respecting trigonometric function, this performs operations "in radians."
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::f64::consts::PI;
+	///
+	/// let a = Term::Cosecant(Box::new(Term::Constant(PI / 2.0)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let b = Term::Cosecant(Box::new(Term::Constant(PI)));
+	/// assert_eq!(b.reduce(), Err(EvalError::DivisionByZero));
+	/// ```
+	Cosecant(Box<Term>),
+	/// Represents the cotangent function.
+	///
+	/// The associated term is evaluated and the ratio of its cosine to its sine is taken. As with `Term::Quotient`, arguments at which the sine is (approximately) zero are rejected.
+	///
+	/// Like any self-respecting trigonometric function, this performs operations "in radians."
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::f64::consts::PI;
+	///
+	/// let a = Term::Cotangent(Box::new(Term::Constant(PI / 4.0)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let b = Term::Cotangent(Box::new(Term::Constant(0.0)));
+	/// assert_eq!(b.reduce(), Err(EvalError::DivisionByZero));
+	/// ```
+	Cotangent(Box<Term>),
+	/// Represents the (Euclidean) remainder of dividing one term by another.
+	///
+	/// The first term is the dividend and the second is the divisor. The result is computed with `f64::rem_euclid`, so it is always non-negative (and less than the magnitude of the divisor); this differs from Rust's `%` operator, whose result takes the sign of the dividend. As with `Term::Quotient`, divisors of (approximately) zero are rejected.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	///
+	/// let a = Term::Modulo(Box::new(Term::Constant(7.0)), Box::new(Term::Constant(3.0)));
+	/// assert!((a.reduce().unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let b = Term::Modulo(Box::new(Term::Constant(-1.0)), Box::new(Term::Constant(3.0)));
+	/// assert!((b.reduce().unwrap() - 2.0).abs() < 0.00001); // Whereas -1.0 % 3.0 == -1.0
+	///
+	/// let c = Term::Modulo(Box::new(Term::Constant(1.0)), Box::new(Term::Constant(0.0)));
+	/// assert_eq!(c.reduce(), Err(EvalError::DivisionByZero));
+	/// ```
+	Modulo(Box<Term>, Box<Term>),
+	/// Represents the factorial of a term.
+	///
+	/// Non-negative integers are evaluated exactly, as the product `1 * 2 * ... * n`; other arguments are extended to the reals through the gamma function (`x! = Γ(x + 1)`), which is approximated with the Lanczos method. Negative integers are poles of the gamma function, and are rejected.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	///
+	/// let a = Term::Factorial(Box::new(Term::Constant(5.0)));
+	/// assert_eq!(a.reduce(), Ok(120.0));
+	///
+	/// let b = Term::Factorial(Box::new(Term::Constant(2.5)));
+	/// assert!((b.reduce().unwrap() - 3.32335097).abs() < 0.00001);
+	///
+	/// let c = Term::Factorial(Box::new(Term::Constant(-2.0)));
+	/// assert_eq!(c.reduce(), Err(EvalError::DomainError { function: "factorial".to_string(), value: -2.0 }));
+	/// ```
+	Factorial(Box<Term>),
+	/// Represents the sum of a term over a range of integers.
+	///
+	/// The lower (`from`) and upper (`to`) bounds are evaluated first, and must both be integers. The body is then evaluated once for each integer from the lower bound to the upper bound (inclusive), with the variable named by `index` bound to that integer, and the results are summed; an empty range sums to zero. The index is local to the body, so it is not a free variable of the summation, and shadows any variable of the same name.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term, Variable};
+	///
+	/// let i = Term::Variable(Variable::named('i'));
+	/// let s = Term::Summation {
+	///     index: "i".to_string(),
+	///     from: Box::new(Term::Constant(1.0)),
+	///     to: Box::new(Term::Constant(100.0)),
+	///     body: Box::new(i)
+	/// };
+	/// assert_eq!(s.reduce(), Ok(5050.0));
+	/// assert!(s.free_variables().is_empty());
+	///
+	/// let t: Term = "sum(i^2, i, 1, n)".parse().unwrap();
+	/// assert_eq!(t.free_variables().into_iter().collect::<Vec<_>>(), vec!("n"));
+	///
+	/// let u: Term = "sum(i, i, 1, 2.5)".parse().unwrap();
+	/// assert_eq!(u.reduce(), Err(EvalError::DomainError { function: "summation bound".to_string(), value: 2.5 }));
+	/// ```
+	Summation {
+		index: String,
+		from: Box<Term>,
+		to: Box<Term>,
+		body: Box<Term>
+	},
+	/// Represents the product of a term over a range of integers.
+	///
+	/// This is the multiplicative counterpart of `Term::Summation`: the body is evaluated once for each integer from the lower bound to the upper bound (inclusive), with the variable named by `index` bound to that integer, and the results are multiplied. An empty range (where the upper bound is less than the lower bound) evaluates to one.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let i = Term::Variable(Variable::named('i'));
+	/// let p = Term::IndexedProduct {
+	///     index: "i".to_string(),
+	///     from: Box::new(Term::Constant(1.0)),
+	///     to: Box::new(Term::Constant(5.0)),
+	///     body: Box::new(i)
+	/// };
+	/// assert_eq!(p.reduce(), Ok(120.0));
+	///
+	/// let empty: Term = "product(i, i, 1, 0)".parse().unwrap();
+	/// assert_eq!(empty.reduce(), Ok(1.0));
+	/// ```
+	IndexedProduct {
+		index: String,
+		from: Box<Term>,
+		to: Box<Term>,
+		body: Box<Term>
+	},
+	/// Represents the constant π, the ratio of a circle's circumference to its diameter.
+	///
+	/// Unlike `Term::Constant(std::f64::consts::PI)`, this remains symbolic until evaluated, and is displayed as `π`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::f64::consts::PI;
+	///
+	/// assert!((Term::Pi.reduce().unwrap() - PI).abs() < 1e-12);
+	/// assert_eq!(&format!("{}", Term::Pi * Term::Constant(2.0)), "π * 2");
+	/// assert_eq!(&Term::Pi.to_latex(), "\\pi");
+	/// ```
+	Pi,
+	/// Represents the constant e, the base of the natural logarithm.
+	///
+	/// Unlike `Term::Constant(std::f64::consts::E)`, this remains symbolic until evaluated, and is displayed as `e`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::f64::consts::E;
+	///
+	/// assert!((Term::E.reduce().unwrap() - E).abs() < 1e-12);
+	/// assert_eq!(&format!("{}", Term::E), "e");
+	/// ```
+	E,
+	/// Represents a term defined by cases.
+	///
+	/// Each branch pairs a condition with the term it selects. On evaluation, the conditions are checked in order (with the same variable values), and the term of the first branch whose condition holds is evaluated; the terms of other branches are not evaluated at all. If no condition holds, evaluation fails with `EvalError::NoMatchingBranch`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{Condition, EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let x = Term::from('x');
+	/// let absolute = Term::Piecewise(vec!(
+	///     (Condition::GreaterThanOrEqual(x.clone(), Term::zero()), x.clone()),
+	///     (Condition::LessThan(x.clone(), Term::zero()), -x.clone())
+	/// ));
+	///
 	/// let mut values = HashMap::new();
-	/// values.insert('x', 28.0);
-	/// assert!((s.evaluate(&values).unwrap() - 128.0).abs() < 0.00001);
+	/// values.insert("x".to_string(), 2.0);
+	/// assert_eq!(absolute.evaluate(&values), Ok(2.0));
+	/// values.insert("x".to_string(), -3.0);
+	/// assert_eq!(absolute.evaluate(&values), Ok(3.0));
+	///
+	/// let positive = Term::Piecewise(vec!((Condition::GreaterThan(x.clone(), Term::zero()), x)));
+	/// assert_eq!(positive.evaluate(&values), Err(EvalError::NoMatchingBranch));
 	/// ```
-	pub fn evaluate(&self, values: &VariableValues) -> Result<f64, String> {
-		self.eval(Some(values))
-	}
-	/// Evaluates a term to its numerical value, assuming only constants (no variables specified).
+	Piecewise(Vec<(Condition, Term)>),
+	/// Represents the floor of a term: the greatest integer less than or equal to it.
 	///
-	/// # Panics
-	/// This method is functionally identical to using `Term::evaluate` with an empty value table, so it inherits the panic conditions from `Term::evaluate`.
-	/// Most significantly, **if a variable is present in `self`, this function will panic**, since the variable value will not be resolved.
-	/// 
-	/// # Examples
+	/// #Examples
 	/// ```
 	/// use cassie::Term;
-	/// let c = Term::Constant(64.0);
-	/// assert!((c.reduce().unwrap() - 64.0) < 0.00001);
 	///
-	/// let b = Term::Constant(64.0);
-	/// let a = Term::Constant(36.0);
-	/// let c = &a + &b;
-	/// assert!(a.reduce().unwrap() - 36.0 < 0.00001);
-	/// assert!(b.reduce().unwrap() - 64.0 < 0.00001);
-	/// assert!(c.reduce().unwrap() - 100.0 < 0.00001);
+	/// let a = Term::Floor(Box::new(Term::Constant(2.7)));
+	/// assert_eq!(a.reduce(), Ok(2.0));
+	///
+	/// let b = Term::Floor(Box::new(Term::Constant(-2.7)));
+	/// assert_eq!(b.reduce(), Ok(-3.0));
 	/// ```
-	pub fn reduce(&self) -> Result<f64, String> {
-		self.eval(None)
-	}
-
-	fn eval(&self, values: Option<&VariableValues>) -> Result<f64, String> {
-		use Term::*;
-		match *self {
-			Constant(value) => Ok(value),
-			Sum(ref terms) => {
-				let mut sum = 0.0;
-				for term in terms {
-					match term.eval(values) {
-						Ok(value) => {
-							sum += value;
-						}, Err(e) => {
-							return Err(e);
-						}
-					};
-				}
-				Ok(sum) // dim sum for a twosome
-			}, Difference(ref terms) => {
-				let first = terms[0].eval(values);
-				if first.is_err() { return first; }
-				let mut difference = first.unwrap();
-				for term in terms[1..].iter() {
-					match term.eval(values) {
-						Ok(value) => {
-							difference -= value;
-						}, Err(e) => {
-							return Err(e);
-						}
-					};
-				}
-				Ok(difference)
-			}, Product(ref terms) => {
-				let mut product = 1.0;
-				for term in terms {
-					match term.eval(values) {
-						Ok(value) => {
-							product *= value;
-						}, Err(e) => {
-							return Err(e);
-						}
-					};
-				}
-				Ok(product)
-			}, Quotient(ref terms) => {
-				let first = terms[0].eval(values);
-				if first.is_err() { return first; }
-				let mut quotient = first.unwrap();
-				for term in terms[1..].iter() {
-					match term.eval(values) {
-						Ok(dividend) => {
-							if dividend.abs() <  0.00000000000000001 {
-								return Err("Attempted division by zero.".to_string());
-							}
-							quotient /= dividend;
-						}, Err(e) => {
-							return Err(e);
-						}
-					};
-				}
-				Ok(quotient)
-			}, Variable(ref variable) => {
-				if let Some(v) = values {
-					if let Some(value) = v.get(&variable.symbol) {
-						Ok(*value)
-					} else {
-						Err(format!("No value provided for variable {}", variable.symbol))
-					}
-				} else {
-					Err(format!("No variable values provided (looking for {})", variable.symbol))
-				}
-			}, Sine(ref term) => {
-				match term.eval(values) {
-					Ok(value) => Ok(value.sin()),
-					Err(e) => Err(e)
-				}
-			}, Cosine(ref term) => {
-				match term.eval(values) {
-					Ok(value) => Ok(value.cos()),
-					Err(e) => Err(e)
-				}
-			}, ArcSine(ref term) => {
-				match term.eval(values) {
-					Ok(value) => Ok(value.asin()),
-					Err(e) => Err(e)
-				}
-			}, ArcCosine(ref term) => {
-				match term.eval(values) {
-					Ok(value) => Ok(value.acos()),
-					Err(e) => Err(e)
-				}
-			}, Tangent(ref term) => {
-				match term.eval(values) {
-					Ok(value) => Ok(value.tan()),
-					Err(e) => Err(e)
-				}
-			}, ArcTangent(ref term) => {
-				match term.eval(values) {
-					Ok(value) => Ok(value.atan()),
-					Err(e) => Err(e)
-				}
-			}
-		}
-	}
-}
-
-impl<'a, 'b> Add<&'b Term> for &'a Term {
-
-	type Output = Term;
-
-	fn add(self, another: &'b Term) -> Term {
-		Term::Sum(vec!(self.clone(), another.clone()))
-	}
-}
-
+	Floor(Box<Term>),
+	/// Represents the ceiling of a term: the least integer greater than or equal to it.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Ceiling(Box::new(Term::Constant(2.1)));
+	/// assert_eq!(a.reduce(), Ok(3.0));
+	///
+	/// let b: Term = "ceil(x) - floor(x)".parse().unwrap();
+	/// assert_eq!(&format!("{}", b), "ceil(x) - floor(x)");
+	/// ```
+	Ceiling(Box<Term>),
+	/// Represents the sign (signum) of a term: `-1` for negative values, `1` for positive values, and `0` for zero.
+	///
+	/// Note that, following mathematical convention, the sign of zero (of either sign) is zero, unlike `f64::signum`, which gives `1.0` for `0.0`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// assert_eq!(Term::Sign(Box::new(Term::Constant(-2.5))).reduce(), Ok(-1.0));
+	/// assert_eq!(Term::Sign(Box::new(Term::Constant(0.0))).reduce(), Ok(0.0));
+	/// assert_eq!(Term::Sign(Box::new(Term::Constant(3.0))).reduce(), Ok(1.0));
+	/// ```
+	Sign(Box<Term>),
+	/// Represents the greatest of any number of terms.
+	///
+	/// All of the terms are evaluated (so any error among them is reported), and the greatest value is taken. A maximum of no terms at all has no value, and produces `EvalError::NoOperands`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term, Variable};
+	/// use std::collections::HashMap;
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let relu = Term::Maximum(vec!(x, Term::Constant(0.0)));
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), -5.0);
+	/// assert_eq!(relu.evaluate(&values), Ok(0.0));
+	/// values.insert("x".to_string(), 5.0);
+	/// assert_eq!(relu.evaluate(&values), Ok(5.0));
+	///
+	/// assert_eq!(Term::Maximum(vec!()).reduce(), Err(EvalError::NoOperands("max".to_string())));
+	/// ```
+	Maximum(Vec<Term>),
+	/// Represents the least of any number of terms.
+	///
+	/// All of the terms are evaluated (so any error among them is reported), and the least value is taken. A minimum of no terms at all has no value, and produces `EvalError::NoOperands`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "min(3, 1 + 1, 4)".parse().unwrap();
+	/// assert_eq!(t.reduce(), Ok(2.0));
+	/// assert_eq!(&format!("{}", t), "min(3, 1 + 1, 4)");
+	/// ```
+	Minimum(Vec<Term>),
+	/// Represents a term rounded to the nearest integer.
+	///
+	/// Halfway cases are rounded away from zero (as by `f64::round`), rather than to the nearest even integer ("banker's rounding"), so `2.5` rounds to `3` and `-2.5` to `-3`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// assert_eq!(Term::Round(Box::new(Term::Constant(2.5))).reduce(), Ok(3.0));
+	/// assert_eq!(Term::Round(Box::new(Term::Constant(-2.5))).reduce(), Ok(-3.0));
+	/// assert_eq!(Term::Round(Box::new(Term::Constant(2.4))).reduce(), Ok(2.0));
+	/// ```
+	Round(Box<Term>),
+	/// Represents the integer part of a term, rounding towards zero.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// assert_eq!(Term::Truncate(Box::new(Term::Constant(2.9))).reduce(), Ok(2.0));
+	/// assert_eq!(Term::Truncate(Box::new(Term::Constant(-2.9))).reduce(), Ok(-2.0));
+	/// ```
+	Truncate(Box<Term>),
+	/// Represents the fractional part of a term: the term less its integer part (see `Term::Truncate`).
+	///
+	/// The fractional part takes the sign of the term, so that of `-2.25` is `-0.25`.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// assert_eq!(Term::Fractional(Box::new(Term::Constant(2.25))).reduce(), Ok(0.25));
+	/// assert_eq!(Term::Fractional(Box::new(Term::Constant(-2.25))).reduce(), Ok(-0.25));
+	/// ```
+	Fractional(Box<Term>),
+	/// Represents a term clamped to lie between a lower (`min`) and an upper (`max`) bound.
+	///
+	/// All three terms are evaluated, and the value is replaced by the nearer bound if it lies outside them. The lower bound must not exceed the upper bound; if it does (or if either bound is `NaN`), an `EvalError::DomainError` is produced for the lower bound.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	///
+	/// let clamp = |value: f64, min: f64, max: f64| Term::Clamp {
+	///     value: Box::new(Term::Constant(value)),
+	///     min: Box::new(Term::Constant(min)),
+	///     max: Box::new(Term::Constant(max))
+	/// };
+	/// assert_eq!(clamp(5.0, 0.0, 3.0).reduce(), Ok(3.0));
+	/// assert_eq!(clamp(-1.0, 0.0, 3.0).reduce(), Ok(0.0));
+	/// assert_eq!(clamp(2.0, 0.0, 3.0).reduce(), Ok(2.0));
+	/// assert_eq!(clamp(2.0, 3.0, 0.0).reduce(), Err(EvalError::DomainError { function: "clamp bounds".to_string(), value: 3.0 }));
+	///
+	/// let t: Term = "clamp(x, 0, 1)".parse().unwrap();
+	/// assert_eq!(&format!("{}", t), "clamp(x, 0, 1)");
+	/// ```
+	Clamp {
+		value: Box<Term>,
+		min: Box<Term>,
+		max: Box<Term>
+	},
+	/// Represents a term raised to a constant integer power.
+	///
+	/// This is equivalent to `Term::Power` with a constant integer exponent, but is evaluated by repeated multiplication, which is faster and more accurate for small exponents. Negative exponents produce reciprocals, and so are an `EvalError::DivisionByZero` for a base of (approximately) zero.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	///
+	/// let cube = Term::PowerInt(Box::new(Term::Constant(2.0)), 3);
+	/// assert_eq!(cube.reduce(), Ok(8.0));
+	///
+	/// let reciprocal = Term::PowerInt(Box::new(Term::Constant(4.0)), -1);
+	/// assert_eq!(reciprocal.reduce(), Ok(0.25));
+	/// let reciprocal = Term::PowerInt(Box::new(Term::Constant(0.0)), -1);
+	/// assert_eq!(reciprocal.reduce(), Err(EvalError::DivisionByZero));
+	///
+	/// assert_eq!(&format!("{}", Term::from('x').powi(-2)), "x^(-2)");
+	/// ```
+	PowerInt(Box<Term>, i32),
+	/// Represents the derivative of a term with respect to a variable, left unevaluated until it is needed.
+	///
+	/// The derivative is computed symbolically (see `Term::derivative`) only when the term is evaluated, differentiated, or simplified (which replaces it with the computed derivative). Other variables in the body are unaffected, so that substituting for them works as usual, whereas substituting for `variable` itself first computes the derivative.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let d = Term::Derivative { body: Box::new("x^2".parse().unwrap()), variable: "x".to_string() };
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 3.0);
+	/// assert_eq!(d.evaluate(&values), Ok(6.0));
+	///
+	/// assert_eq!(&format!("{}", d), "derivative(x^2, x)");
+	/// assert_eq!(&format!("{}", d.simplify()), "2 * x");
+	/// ```
+	Derivative {
+		body: Box<Term>,
+		variable: String
+	},
+	/// Represents the two-argument inverse tangent: the angle between the positive x-axis and the point (`x`, `y`).
+	///
+	/// Unlike `Term::ArcTangent` applied to `y / x`, this distinguishes all four quadrants, producing angles between -π and π. As with `f64::atan2`, the angle of the origin is zero rather than an error.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::f64::consts;
+	///
+	/// let atan2 = |y: f64, x: f64| Term::Atan2 { y: Box::new(Term::Constant(y)), x: Box::new(Term::Constant(x)) };
+	/// assert!((atan2(1.0, 1.0).reduce().unwrap() - consts::FRAC_PI_4).abs() < 1e-12);
+	/// assert!((atan2(-1.0, -1.0).reduce().unwrap() + 3.0 * consts::FRAC_PI_4).abs() < 1e-12);
+	/// assert_eq!(atan2(0.0, 0.0).reduce(), Ok(0.0));
+	///
+	/// let t: Term = "atan2(y, x)".parse().unwrap();
+	/// assert_eq!(&format!("{}", t), "atan2(y, x)");
+	/// ```
+	Atan2 {
+		y: Box<Term>,
+		x: Box<Term>
+	},
+	/// Represents the logistic sigmoid function, `1 / (1 + e^-x)`.
+	///
+	/// This is evaluated in a numerically stable form, so that it approaches zero rather than overflowing for large negative terms.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let sigmoid = |value: f64| Term::Sigmoid(Box::new(Term::Constant(value)));
+	/// assert_eq!(sigmoid(0.0).reduce(), Ok(0.5));
+	/// assert_eq!(sigmoid(-1000.0).reduce(), Ok(0.0));
+	/// assert_eq!(sigmoid(1000.0).reduce(), Ok(1.0));
+	///
+	/// let t: Term = "sigmoid(x)".parse().unwrap();
+	/// assert_eq!(&format!("{}", t), "sigmoid(x)");
+	/// ```
+	Sigmoid(Box<Term>),
+	/// Represents the conversion of a term from degrees to radians.
+	///
+	/// This allows angles in degrees to be passed to the trigonometric functions (which operate in radians) within a term, independently of any angle mode.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let right_angle = Term::ToRadians(Box::new(Term::Constant(90.0)));
+	/// assert_eq!(Term::Sine(Box::new(right_angle)).reduce(), Ok(1.0));
+	///
+	/// let t: Term = "radians(x)".parse().unwrap();
+	/// assert_eq!(&format!("{}", t), "radians(x)");
+	/// ```
+	ToRadians(Box<Term>),
+	/// Represents the conversion of a term from radians to degrees.
+	///
+	/// This allows the results of the inverse trigonometric functions (which are in radians) to be expressed in degrees within a term, independently of any angle mode.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "degrees(atan(1))".parse().unwrap();
+	/// assert!((t.reduce().unwrap() - 45.0).abs() < 1e-12);
+	/// assert_eq!(&format!("{}", t), "degrees(atan(1))");
+	/// ```
+	ToDegrees(Box<Term>),
+	/// Represents the binomial coefficient "`n` choose `k`": the number of ways of choosing `k` items from `n`.
+	///
+	/// Both terms must evaluate to non-negative integers, with `k` no greater than `n`; otherwise, an `EvalError::DomainError` is produced for the offending term. The coefficient is computed by alternately multiplying and dividing, so that each partial result is itself a binomial coefficient (`n - k + i` choose `i`, after `i` steps); results are therefore exact as long as each product `coefficient * (n - k + i)` fits within 2^53, and very large coefficients may be infinite.
+	///
+	/// #Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	///
+	/// let binomial = |n: f64, k: f64| Term::Binomial { n: Box::new(Term::Constant(n)), k: Box::new(Term::Constant(k)) };
+	/// assert_eq!(binomial(5.0, 2.0).reduce(), Ok(10.0));
+	/// assert_eq!(binomial(50.0, 25.0).reduce(), Ok(126410606437752.0));
+	/// assert_eq!(binomial(2.0, 5.0).reduce(), Err(EvalError::DomainError { function: "binomial".to_string(), value: 5.0 }));
+	/// assert!(binomial(2.5, 1.0).reduce().is_err());
+	///
+	/// let t: Term = "binomial(n, 2)".parse().unwrap();
+	/// assert_eq!(&format!("{}", t), "binomial(n, 2)");
+	/// ```
+	Binomial {
+		n: Box<Term>,
+		k: Box<Term>
+	}
+}
+
+impl Term {
+	/// Evaluates a term to its numerical value.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	/// use std::collections::HashMap;
+	///
+	/// let x: Variable = "x".parse().unwrap();
+	/// let x = Term::Variable(x);
+	/// let c = Term::Constant(100.0);
+	/// let s = x + c;
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 28.0);
+	/// assert!((s.evaluate(&values).unwrap() - 128.0).abs() < 0.00001);
+	/// ```
+	pub fn evaluate(&self, values: &VariableValues) -> Result<f64, EvalError> {
+		self.eval(Some(values), AngleMode::Radians)
+	}
+	/// Evaluates a term to its numerical value, measuring angles in the given unit.
+	///
+	/// In `AngleMode::Degrees`, the arguments of the trigonometric functions are converted from degrees to radians before being applied, and the results of the inverse trigonometric functions are converted back to degrees. `Term::evaluate` is equivalent to using `AngleMode::Radians`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{AngleMode, Term, Variable};
+	/// use std::collections::HashMap;
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let s = Term::Sine(Box::new(x));
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 90.0);
+	/// assert!((s.evaluate_with_mode(&values, AngleMode::Degrees).unwrap() - 1.0).abs() < 0.00001);
+	///
+	/// let a = Term::ArcTangent(Box::new(Term::Constant(1.0)));
+	/// assert!((a.evaluate_with_mode(&HashMap::new(), AngleMode::Degrees).unwrap() - 45.0).abs() < 0.00001);
+	/// ```
+	pub fn evaluate_with_mode(&self, values: &VariableValues, mode: AngleMode) -> Result<f64, EvalError> {
+		self.eval(Some(values), mode)
+	}
+	/// Evaluates a term to its numerical value, treating divisors as zero only if they are within `epsilon` of it.
+	///
+	/// This applies to the divisors of quotients and moduli, and to the bases of negative integer powers (see `Term::PowerInt`). `Term::evaluate` rejects divisors smaller in magnitude than `1e-17`, which may be too strict for data involving legitimately tiny values (or not strict enough to catch divisors which are only zero up to rounding error). Exact zeros are always rejected, so an `epsilon` of zero rejects only those. Errors are those of `Term::evaluate`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "1 / x".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 1e-18);
+	/// assert!(t.evaluate(&values).is_err());
+	/// assert!((t.evaluate_with_epsilon(&values, 0.0).unwrap() - 1e18).abs() < 1e3);
+	///
+	/// values.insert("x".to_string(), 1e-15);
+	/// assert!(t.evaluate(&values).is_ok());
+	/// assert!(t.evaluate_with_epsilon(&values, 1e-12).is_err());
+	///
+	/// values.insert("x".to_string(), 0.0);
+	/// assert_eq!(t.evaluate_with_epsilon(&values, 0.0), Err(EvalError::DivisionByZero));
+	///
+	/// let t: Term = "mod(3, x) + powi(x, -1)".parse().unwrap();
+	/// values.insert("x".to_string(), 1e-18);
+	/// assert!(t.evaluate(&values).is_err());
+	/// assert!(t.evaluate_with_epsilon(&values, 0.0).is_ok());
+	///
+	/// // The threshold also applies within indexed and piecewise terms.
+	/// let t: Term = "sum(1 / (i * x), i, 1, 3)".parse().unwrap();
+	/// values.insert("x".to_string(), 1e-15);
+	/// assert!(t.evaluate(&values).is_ok());
+	/// assert_eq!(t.evaluate_with_epsilon(&values, 1e-12), Err(EvalError::DivisionByZero));
+	/// let t: Term = "piecewise(x > 0, 1 / x)".parse().unwrap();
+	/// assert_eq!(t.evaluate_with_epsilon(&values, 1e-12), Err(EvalError::DivisionByZero));
+	/// ```
+	pub fn evaluate_with_epsilon(&self, values: &VariableValues, epsilon: f64) -> Result<f64, EvalError> {
+		self.eval_with_epsilon(Some(values), AngleMode::Radians, epsilon)
+	}
+	/// Evaluates a term against each of several sets of variable values, in order.
+	///
+	/// The results are exactly those of calling `Term::evaluate` with each set in turn, but a term is first checked for free variables, and one without any is evaluated only once regardless of the number of sets.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x^2 + 1".parse().unwrap();
+	/// let sets = (0..3).map(|x| {
+	///     let mut values = HashMap::new();
+	///     values.insert("x".to_string(), f64::from(x));
+	///     values
+	/// }).collect::<Vec<_>>();
+	/// assert_eq!(t.evaluate_many(&sets), vec!(Ok(1.0), Ok(2.0), Ok(5.0)));
+	/// ```
+	pub fn evaluate_many(&self, binding_sets: &[VariableValues]) -> Vec<Result<f64, EvalError>> {
+		if self.free_variables().is_empty() {
+			if let Some(values) = binding_sets.first() {
+				let result = self.evaluate(values);
+				return vec!(result; binding_sets.len());
+			}
+		}
+		binding_sets.iter().map(|values| self.evaluate(values)).collect()
+	}
+	/// Evaluates a term to its numerical value, using `default` as the value of any variable missing from `values`.
+	///
+	/// Since no variable can be undefined, this never fails with `EvalError::UndefinedVariable`; other errors (such as division by zero) are still reported as they would be by `Term::evaluate`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let s: Term = "x + y".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// assert_eq!(s.evaluate_with_defaults(&values, 0.0), Ok(2.0));
+	///
+	/// let q: Term = "x / y".parse().unwrap();
+	/// assert_eq!(q.evaluate_with_defaults(&values, 0.0), Err(EvalError::DivisionByZero));
+	/// ```
+	pub fn evaluate_with_defaults(&self, values: &VariableValues, default: f64) -> Result<f64, EvalError> {
+		let mut values = values.clone();
+		for symbol in self.free_variables() {
+			values.entry(symbol).or_insert(default);
+		}
+		self.evaluate(&values)
+	}
+	/// Evaluates a term to its numerical value, falling back to case-insensitive matching for variables missing from `values`.
+	///
+	/// A variable with no binding for its exact symbol takes the value of the binding whose symbol differs from it only by case, if there is exactly one; if there are several, the lookup is ambiguous and an `EvalError::AmbiguousVariable` is produced. Other errors are those of `Term::evaluate`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "X + 1".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 5.0);
+	/// assert_eq!(t.evaluate_case_insensitive(&values), Ok(6.0));
+	///
+	/// // Exact matches take precedence.
+	/// values.insert("X".to_string(), 2.0);
+	/// assert_eq!(t.evaluate_case_insensitive(&values), Ok(3.0));
+	///
+	/// let t: Term = "Y".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("y".to_string(), 1.0);
+	/// values.insert("yY".to_string(), 2.0);
+	/// values.insert("Yy".to_string(), 3.0);
+	/// assert_eq!(t.evaluate_case_insensitive(&values), Ok(1.0));
+	/// let t: Term = "YY".parse().unwrap();
+	/// assert_eq!(t.evaluate_case_insensitive(&values), Err(EvalError::AmbiguousVariable {
+	///     symbol: "YY".to_string(),
+	///     candidates: vec!("Yy".to_string(), "yY".to_string())
+	/// }));
+	/// ```
+	pub fn evaluate_case_insensitive(&self, values: &VariableValues) -> Result<f64, EvalError> {
+		let mut bindings = values.clone();
+		for symbol in self.free_variables() {
+			if values.contains_key(&symbol) {
+				continue;
+			}
+			let folded = symbol.to_lowercase();
+			let mut matches = values.keys().filter(|other| other.to_lowercase() == folded).collect::<Vec<_>>();
+			matches.sort();
+			match matches.len() {
+				0 => (),
+				1 => {
+					bindings.insert(symbol, values[matches[0]]);
+				},
+				_ => {
+					let candidates = matches.into_iter().cloned().collect();
+					return Err(EvalError::AmbiguousVariable { symbol, candidates });
+				}
+			}
+		}
+		self.evaluate(&bindings)
+	}
+	/// Evaluates a term to its numerical value, reporting every error encountered rather than only the first.
+	///
+	/// Each subterm whose own operands could all be evaluated is applied as by `Term::evaluate`, so independent problems in different parts of a term (such as several undefined variables) are all reported, in the order in which `Term::evaluate` would encounter them. Subterms depending on a failed subterm are skipped, and repeated errors are reported only once. On success, the value is that of `Term::evaluate`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let s: Term = "x * y + x / z".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("z".to_string(), 2.0);
+	/// assert_eq!(s.evaluate_checked(&values), Err(vec!(
+	///     EvalError::UndefinedVariable("x".to_string()),
+	///     EvalError::UndefinedVariable("y".to_string())
+	/// )));
+	///
+	/// values.insert("x".to_string(), 3.0);
+	/// values.insert("y".to_string(), 4.0);
+	/// assert_eq!(s.evaluate_checked(&values), Ok(13.5));
+	/// ```
+	pub fn evaluate_checked(&self, values: &VariableValues) -> Result<f64, Vec<EvalError>> {
+		let mut errors = vec!();
+		let value = self.evaluate_postorder(|term, operands: Vec<Option<f64>>| {
+			// A subterm is only applied once all of its operands are known; otherwise, its failure has already been reported.
+			let operands = match operands.into_iter().collect::<Option<Vec<_>>>() {
+				Some(operands) => operands,
+				None => return Ok(None)
+			};
+			match term.apply_dividing(&operands, Some(values), AngleMode::Radians) {
+				Ok(value) => Ok(Some(value)),
+				Err(error) => {
+					if !errors.contains(&error) {
+						errors.push(error);
+					}
+					Ok(None)
+				}
+			}
+		}).unwrap_or_else(|never: Infallible| match never {});
+		match value {
+			Some(value) => Ok(value),
+			None => Err(errors)
+		}
+	}
+	/// Evaluates a term to its numerical value, failing as soon as any subterm evaluates to an infinite or `NaN` value.
+	///
+	/// Floating-point overflow (as in `exp(1000)`) and invalid operations (as in `0 * inf`) otherwise propagate silently through the rest of a term; here, they are reported as an `EvalError::NonFinite` naming the offending subterm along with its value. Other errors are those of `Term::evaluate`. Indexed and piecewise terms evaluate their own subterms, so only their results are checked.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// use std::collections::HashMap;
+	///
+	/// let t = Term::Exp(Box::new(Term::Constant(1000.0)));
+	/// assert_eq!(t.evaluate(&HashMap::new()), Ok(std::f64::INFINITY));
+	/// let error = EvalError::NonFinite { term: "exp(1000)".to_string(), value: std::f64::INFINITY };
+	/// assert_eq!(t.evaluate_finite(&HashMap::new()), Err(error));
+	///
+	/// let t: Term = "1 / exp(x)".parse().unwrap();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 1000.0);
+	/// assert_eq!(t.evaluate(&values), Ok(0.0));
+	/// assert!(t.evaluate_finite(&values).is_err());
+	///
+	/// values.insert("x".to_string(), 0.0);
+	/// assert_eq!(t.evaluate_finite(&values), Ok(1.0));
+	/// ```
+	pub fn evaluate_finite(&self, values: &VariableValues) -> Result<f64, EvalError> {
+		self.evaluate_postorder(|term, operands: Vec<f64>| {
+			let value = term.apply_dividing(&operands, Some(values), AngleMode::Radians)?;
+			if value.is_finite() {
+				Ok(value)
+			} else {
+				Err(EvalError::NonFinite { term: term.to_string(), value })
+			}
+		})
+	}
+	/// Evaluates as much of a term as possible, leaving unbound variables symbolic.
+	///
+	/// Each variable with a value in `values` is replaced by that value, and the result is simplified (see `Term::simplify`). A term whose variables are all bound therefore collapses to a single `Term::Constant`, while one with unbound variables retains the structure surrounding them.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let s = Term::from('x') + Term::from('y');
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// assert_eq!(s.evaluate_partial(&values), Term::Constant(2.0) + Term::from('y'));
+	///
+	/// values.insert("y".to_string(), 3.0);
+	/// assert_eq!(s.evaluate_partial(&values), Term::Constant(5.0));
+	/// ```
+	pub fn evaluate_partial(&self, values: &VariableValues) -> Term {
+		self.bind(values).simplify()
+	}
+	/// Evaluates a term to its numerical value, assuming only constants (no variables specified).
+	///
+	/// # Errors
+	/// This method is functionally identical to using `Term::evaluate` with an empty value table, so it produces the same errors as `Term::evaluate`.
+	/// Most significantly, if a variable is present in `self`, an `EvalError::NoBindings` is produced for it, since the variable value cannot be resolved.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{EvalError, Term};
+	/// let c = Term::Constant(64.0);
+	/// assert!((c.reduce().unwrap() - 64.0) < 0.00001);
+	///
+	/// let b = Term::Constant(64.0);
+	/// let a = Term::Constant(36.0);
+	/// let c = &a + &b;
+	/// assert!(a.reduce().unwrap() - 36.0 < 0.00001);
+	/// assert!(b.reduce().unwrap() - 64.0 < 0.00001);
+	/// assert!(c.reduce().unwrap() - 100.0 < 0.00001);
+	///
+	/// let x = Term::from('x');
+	/// assert_eq!(x.reduce(), Err(EvalError::NoBindings("x".to_string())));
+	/// ```
+	pub fn reduce(&self) -> Result<f64, EvalError> {
+		self.eval(None, AngleMode::Radians)
+	}
+
+	/// Creates a constant term, rejecting values which are not finite.
+	///
+	/// A `Term::Constant` holding `NaN` or an infinity poisons every evaluation of any term containing it, so this is the preferred way to turn untrusted numbers into terms. The variant itself may still be constructed directly where such values are intended.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// assert_eq!(Term::constant(3.0), Ok(Term::Constant(3.0)));
+	/// assert!(Term::constant(f64::NAN).is_err());
+	/// assert!(Term::constant(f64::INFINITY).is_err());
+	/// ```
+	pub fn constant(value: f64) -> Result<Term, String> {
+		if value.is_finite() {
+			Ok(Term::Constant(value))
+		} else {
+			Err(format!("Constants must be finite ({} given).", value))
+		}
+	}
+	/// The additive identity, `Term::Constant(0.0)`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// assert_eq!(Term::zero(), Term::Constant(0.0));
+	/// ```
+	pub fn zero() -> Term {
+		Term::Constant(0.0)
+	}
+	/// The multiplicative identity, `Term::Constant(1.0)`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// assert_eq!(Term::one(), Term::Constant(1.0));
+	/// ```
+	pub fn one() -> Term {
+		Term::Constant(1.0)
+	}
+	/// Raises a term to a constant integer power, as a `Term::PowerInt`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let cube = Term::from('x').powi(3);
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 2.0);
+	/// assert_eq!(cube.evaluate(&values), Ok(8.0));
+	/// assert_eq!(Term::from('x').powi(-2).evaluate(&values), Ok(0.25));
+	/// ```
+	pub fn powi(self, exponent: i32) -> Term {
+		Term::PowerInt(Box::new(self), exponent)
+	}
+	/// Whether a term is a constant equal to zero (within `f64::EPSILON`).
+	///
+	/// Only `Term::Constant` terms are considered; compound terms which happen to evaluate to zero are not.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// assert!(Term::zero().is_zero());
+	/// assert!(Term::Constant(-0.0).is_zero());
+	/// assert!(!Term::one().is_zero());
+	/// assert!(!(Term::zero() + Term::zero()).is_zero());
+	/// ```
+	pub fn is_zero(&self) -> bool {
+		match *self {
+			Term::Constant(value) => value.abs() < f64::EPSILON,
+			_ => false
+		}
+	}
+	/// Whether a term is a constant equal to one (within `f64::EPSILON`).
+	///
+	/// Only `Term::Constant` terms are considered; compound terms which happen to evaluate to one are not.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// assert!(Term::one().is_one());
+	/// assert!(!Term::zero().is_one());
+	/// assert!(!(Term::one() * Term::one()).is_one());
+	/// ```
+	pub fn is_one(&self) -> bool {
+		match *self {
+			Term::Constant(value) => (value - 1.0).abs() < f64::EPSILON,
+			_ => false
+		}
+	}
+	/// The value of a term, if it is a `Term::Constant`.
+	///
+	/// Only `Term::Constant` terms are considered; `Term::Pi`, `Term::E`, and compound terms yield `None`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// assert_eq!(Term::Constant(3.0).as_constant(), Some(3.0));
+	/// assert_eq!(Term::Pi.as_constant(), None);
+	/// ```
+	pub fn as_constant(&self) -> Option<f64> {
+		match *self {
+			Term::Constant(value) => Some(value),
+			_ => None
+		}
+	}
+	/// The variable a term refers to, if it is a `Term::Variable`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	/// let x = Variable::named('x');
+	/// assert_eq!(Term::Variable(x.clone()).as_variable(), Some(&x));
+	/// assert_eq!(Term::Constant(3.0).as_variable(), None);
+	/// ```
+	pub fn as_variable(&self) -> Option<&Variable> {
+		match *self {
+			Term::Variable(ref variable) => Some(variable),
+			_ => None
+		}
+	}
+
+	/// Counts the operations in a term, as a rough measure of its complexity.
+	///
+	/// Every compound term (sums, differences, products, quotients, and function applications alike) counts as one operation, while constants (including `Term::Pi` and `Term::E`) and variables count as none.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "sin(x) + 2".parse().unwrap();
+	/// assert_eq!(t.count_operations(), 2);
+	/// assert_eq!(Term::Constant(2.0).count_operations(), 0);
+	/// ```
+	pub fn count_operations(&self) -> usize {
+		match *self {
+			Term::Constant(_) | Term::Variable(_) | Term::Pi | Term::E => 0,
+			_ => 1 + self.children().into_iter().map(Term::count_operations).sum::<usize>()
+		}
+	}
+
+	/// Measures the height of a term's expression tree.
+	///
+	/// Constants and variables have a depth of one; a compound term is one deeper than its deepest subterm. Like evaluation, this does not recurse, so it is safe to call on arbitrarily deep terms.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "sin(x + 1)".parse().unwrap();
+	/// assert_eq!(t.depth(), 3);
+	/// assert_eq!(Term::Constant(2.0).depth(), 1);
+	/// ```
+	pub fn depth(&self) -> usize {
+		let mut pending = vec!((self, 1));
+		let mut depth = 0;
+		while let Some((term, level)) = pending.pop() {
+			depth = depth.max(level);
+			pending.extend(term.children().into_iter().map(|child| (child, level + 1)));
+		}
+		depth
+	}
+
+	/// Iterates over every subterm of a term (including the term itself and its leaves), in pre-order.
+	///
+	/// Each term is yielded before its children, which are visited in order (see `Term::children`); the operands of piecewise conditions, and the bounds and bodies of summations and indexed products, are included. Like evaluation, this does not recurse.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "sin(x) + 1".parse().unwrap();
+	/// let subterms = t.iter_subterms().map(|term| format!("{}", term)).collect::<Vec<_>>();
+	/// assert_eq!(subterms, vec!("sin(x) + 1", "sin(x)", "x", "1"));
+	/// ```
+	pub fn iter_subterms(&self) -> impl Iterator<Item = &Term> {
+		let mut pending = vec!(self);
+		iter::from_fn(move || {
+			let term = pending.pop()?;
+			pending.extend(term.children().into_iter().rev());
+			Some(term)
+		})
+	}
+
+	/// Counts the nodes in a term's expression tree, including leaves.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x + 1".parse().unwrap();
+	/// assert_eq!(t.num_nodes(), 3);
+	/// assert_eq!(Term::Constant(2.0).num_nodes(), 1);
+	/// ```
+	pub fn num_nodes(&self) -> usize {
+		self.iter_subterms().count()
+	}
+
+	/// Collects the symbols of all variables appearing in a term.
+	///
+	/// This is the set of symbols for which values must be supplied to `Term::evaluate`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let y = Term::Variable(Variable::named('y'));
+	/// let s = x + y * Term::Constant(2.0);
+	/// let free = s.free_variables();
+	/// assert_eq!(free.len(), 2);
+	/// assert!(free.contains("x"));
+	/// assert!(free.contains("y"));
+	///
+	/// assert!(Term::Constant(2.0).free_variables().is_empty());
+	/// ```
+	pub fn free_variables(&self) -> HashSet<String> {
+		let mut variables = HashSet::new();
+		self.collect_variables(&mut variables);
+		variables
+	}
+
+	/// Replaces every occurrence of the variable `symbol` with `replacement`.
+	///
+	/// Variables with other symbols are left untouched.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	/// use std::collections::HashMap;
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let t = Term::Variable(Variable::named('t'));
+	/// let s = &x * &x + Term::Constant(3.0);
+	/// let s = s.substitute("x", &(t + Term::Constant(1.0)));
+	/// assert!(!s.free_variables().contains("x"));
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("t".to_string(), 2.0);
+	/// assert!((s.evaluate(&values).unwrap() - 12.0).abs() < 0.00001);
+	/// ```
+	pub fn substitute(&self, symbol: &str, replacement: &Term) -> Term {
+		match *self {
+			Term::Variable(ref variable) if variable.symbol == symbol => replacement.clone(),
+			// The derivative must be taken before the variable it is taken with respect to is replaced.
+			Term::Derivative { ref body, ref variable } if variable == symbol => body.derivative(variable).substitute(symbol, replacement),
+			_ => match self.indexed() {
+				// The index shadows `symbol` throughout the body, so only the bounds are affected.
+				Some((index, _, _, body)) if index == symbol => self.map_children(|child| {
+					if ptr::eq(child, body) { child.clone() } else { child.substitute(symbol, replacement) }
+				}),
+				_ => self.map_children(|child| child.substitute(symbol, replacement))
+			}
+		}
+	}
+
+	/// Replaces every occurrence of each variable with a symbol in `replacements` by the corresponding term, simultaneously.
+	///
+	/// All of the substitutions are made in a single traversal, so each variable in the original term is replaced exactly once; variables appearing in the replacements are not themselves substituted. This differs from substituting each variable in turn with `Term::substitute`, whose later substitutions would apply to the replacements made by earlier ones.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "x - 2 * y".parse().unwrap();
+	/// let mut replacements = HashMap::new();
+	/// replacements.insert("x".to_string(), Term::from('y'));
+	/// replacements.insert("y".to_string(), Term::from('x'));
+	/// let swapped = t.substitute_many(&replacements);
+	/// assert_eq!(&format!("{}", swapped), "y - 2 * x");
+	///
+	/// // Whereas substituting one at a time loses the distinction between x and y.
+	/// let sequential = t.substitute("x", &Term::from('y')).substitute("y", &Term::from('x'));
+	/// assert_eq!(&format!("{}", sequential), "x - 2 * x");
+	/// ```
+	pub fn substitute_many(&self, replacements: &HashMap<String, Term>) -> Term {
+		match *self {
+			Term::Variable(ref variable) => match replacements.get(&variable.symbol) {
+				Some(replacement) => replacement.clone(),
+				None => self.clone()
+			},
+			Term::Derivative { ref body, ref variable } if replacements.contains_key(variable) => body.derivative(variable).substitute_many(replacements),
+			_ => match self.indexed() {
+				// The index shadows its symbol throughout the body.
+				Some((index, _, _, body)) if replacements.contains_key(index) => {
+					let mut shadowed = replacements.clone();
+					shadowed.remove(index);
+					self.map_children(|child| child.substitute_many(if ptr::eq(child, body) { &shadowed } else { replacements }))
+				},
+				_ => self.map_children(|child| child.substitute_many(replacements))
+			}
+		}
+	}
+
+	/// Renames every occurrence of the variable `from` to `to`, keeping any assumption attached to it.
+	///
+	/// This is a cheaper alternative to `Term::substitute` when the replacement is itself a variable. As with substitution, occurrences within the body of a summation or indexed product whose index is `from` are bound by that index, and so are left alone.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t: Term = "x + x".parse().unwrap();
+	/// let renamed = t.rename_variable("x", "y");
+	/// assert_eq!(&format!("{}", renamed), "y + y");
+	/// let free = renamed.free_variables();
+	/// assert_eq!(free.len(), 1);
+	/// assert!(free.contains("y"));
+	/// ```
+	pub fn rename_variable(&self, from: &str, to: &str) -> Term {
+		match *self {
+			Term::Variable(ref variable) if variable.symbol == from => {
+				let mut variable = variable.clone();
+				variable.symbol = to.to_string();
+				Term::Variable(variable)
+			},
+			Term::Derivative { ref body, ref variable } if variable == from => Term::Derivative { body: Box::new(body.rename_variable(from, to)), variable: to.to_string() },
+			_ => match self.indexed() {
+				Some((index, _, _, body)) if index == from => self.map_children(|child| {
+					if ptr::eq(child, body) { child.clone() } else { child.rename_variable(from, to) }
+				}),
+				_ => self.map_children(|child| child.rename_variable(from, to))
+			}
+		}
+	}
+
+	/// Replaces every subterm structurally equal to `target` with `replacement`.
+	///
+	/// Rewriting proceeds bottom-up: the children of each term are rewritten first, and the resulting term is then replaced if it equals `target`. Replacements are not themselves searched for further matches.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let t = Term::Sum(vec!(Term::Constant(0.0), Term::from('x'), Term::Sine(Box::new(Term::Constant(0.0)))));
+	/// let u = t.replace_subterm(&Term::Constant(0.0), &Term::Constant(1.0));
+	/// assert_eq!(&format!("{}", u), "1 + x + sin(1)");
+	///
+	/// let t: Term = "(x + 1) * sin(x + 1)".parse().unwrap();
+	/// let u = t.replace_subterm(&"x + 1".parse().unwrap(), &Term::from('y'));
+	/// assert_eq!(&format!("{}", u), "y * sin(y)");
+	/// ```
+	pub fn replace_subterm(&self, target: &Term, replacement: &Term) -> Term {
+		let term = self.map_children(|child| child.replace_subterm(target, replacement));
+		if term == *target { replacement.clone() } else { term }
+	}
+
+	/// Applies `f` to the value of every `Term::Constant` in a term, preserving the rest of its structure.
+	///
+	/// Only literal constants are affected; `Term::Pi` and `Term::E` are left as they are.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let t: Term = "2 * x + 3".parse().unwrap();
+	/// let doubled = t.map_constants(|value| value * 2.0);
+	/// assert_eq!(&format!("{}", doubled), "4 * x + 6");
+	///
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 1.0);
+	/// assert!((doubled.evaluate(&values).unwrap() - 10.0).abs() < 0.00001);
+	/// ```
+	pub fn map_constants<F: Fn(f64) -> f64 + Copy>(&self, f: F) -> Term {
+		match *self {
+			Term::Constant(value) => Term::Constant(f(value)),
+			_ => self.map_children(|child| child.map_constants(f))
+		}
+	}
+
+	/// Replaces each variable with a value in `values` by that value.
+	fn bind(&self, values: &VariableValues) -> Term {
+		match *self {
+			Term::Variable(ref variable) => match values.get(&variable.symbol) {
+				Some(value) => Term::Constant(*value),
+				None => self.clone()
+			},
+			Term::Derivative { ref body, ref variable } if values.contains_key(variable) => body.derivative(variable).bind(values),
+			_ => match self.indexed() {
+				Some((index, _, _, body)) if values.contains_key(index) => {
+					let mut shadowed = values.clone();
+					shadowed.remove(index);
+					self.map_children(|child| child.bind(if ptr::eq(child, body) { &shadowed } else { values }))
+				},
+				_ => self.map_children(|child| child.bind(values))
+			}
+		}
+	}
+
+	fn collect_variables(&self, variables: &mut HashSet<String>) {
+		if let Term::Variable(ref variable) = *self {
+			variables.insert(variable.symbol.clone());
+		}
+		if let Some((index, from, to, body)) = self.indexed() {
+			from.collect_variables(variables);
+			to.collect_variables(variables);
+			let mut inner = body.free_variables();
+			inner.remove(index);
+			variables.extend(inner);
+			return;
+		}
+		for child in self.children() {
+			child.collect_variables(variables);
+		}
+	}
+
+	/// Returns the index, lower and upper bounds, and body of an indexed term (such as a summation), whose index is bound within its body.
+	pub(crate) fn indexed(&self) -> Option<(&str, &Term, &Term, &Term)> {
+		match *self {
+			Term::Summation { ref index, ref from, ref to, ref body } |
+			Term::IndexedProduct { ref index, ref from, ref to, ref body } => Some((index, from, to, body)),
+			_ => None
+		}
+	}
+
+	/// Whether a term evaluates its subterms itself (see `Term::apply`), rather than having them evaluated beforehand.
+	///
+	/// This is the case for indexed terms, whose bodies must be evaluated once per index, for piecewise terms, whose branches must only be evaluated when selected, and for derivatives, whose bodies are never evaluated themselves.
+	pub(crate) fn evaluates_own_subterms(&self) -> bool {
+		self.indexed().is_some() || matches!(*self, Term::Piecewise(_) | Term::Derivative { .. })
+	}
+
+	/// Returns the immediate subterms of a term, in order.
+	pub(crate) fn children(&self) -> Vec<&Term> {
+		use Term::*;
+		match *self {
+			Constant(_) | Variable(_) | Pi | E => vec!(),
+			Sum(ref terms) | Difference(ref terms) | Product(ref terms) | Quotient(ref terms) |
+			Maximum(ref terms) | Minimum(ref terms) => terms.iter().collect(),
+			Sine(ref term) | Cosine(ref term) | Tangent(ref term) | ArcSine(ref term) | ArcCosine(ref term) | ArcTangent(ref term) |
+			Exp(ref term) | NaturalLog(ref term) | SquareRoot(ref term) | AbsoluteValue(ref term) | Negation(ref term) |
+			Sinh(ref term) | Cosh(ref term) | Tanh(ref term) |
+			Secant(ref term) | Cosecant(ref term) | Cotangent(ref term) |
+			Factorial(ref term) |
+			Floor(ref term) | Ceiling(ref term) |
+			Sign(ref term) |
+			Round(ref term) | Truncate(ref term) | Fractional(ref term) |
+			PowerInt(ref term, _) |
+			Sigmoid(ref term) |
+			ToRadians(ref term) | ToDegrees(ref term) => vec!(&**term),
+			Derivative { ref body, .. } => vec!(&**body),
+			Power(ref a, ref b) | Logarithm(ref a, ref b) | NthRoot(ref a, ref b) |
+			Modulo(ref a, ref b) => vec!(&**a, &**b),
+			Summation { ref from, ref to, ref body, .. } |
+			IndexedProduct { ref from, ref to, ref body, .. } => vec!(&**from, &**to, &**body),
+			Clamp { ref value, ref min, ref max } => vec!(&**value, &**min, &**max),
+			Atan2 { ref y, ref x } => vec!(&**y, &**x),
+			Binomial { ref n, ref k } => vec!(&**n, &**k),
+			Piecewise(ref branches) => branches.iter().flat_map(|(condition, term)| {
+				let (a, b) = condition.operands();
+				vec!(a, b, term)
+			}).collect()
+		}
+	}
+
+	/// Rebuilds a term with `f` applied to each of its immediate subterms, preserving the term's own structure.
+	pub(crate) fn map_children<F: FnMut(&Term) -> Term>(&self, mut f: F) -> Term {
+		use Term::*;
+		let mut map = |term: &Term| Box::new(f(term));
+		match *self {
+			Constant(_) | Variable(_) | Pi | E => self.clone(),
+			Sum(ref terms) => Sum(terms.iter().map(|term| *map(term)).collect()),
+			Difference(ref terms) => Difference(terms.iter().map(|term| *map(term)).collect()),
+			Product(ref terms) => Product(terms.iter().map(|term| *map(term)).collect()),
+			Quotient(ref terms) => Quotient(terms.iter().map(|term| *map(term)).collect()),
+			Sine(ref term) => Sine(map(term)),
+			Cosine(ref term) => Cosine(map(term)),
+			Tangent(ref term) => Tangent(map(term)),
+			ArcSine(ref term) => ArcSine(map(term)),
+			ArcCosine(ref term) => ArcCosine(map(term)),
+			ArcTangent(ref term) => ArcTangent(map(term)),
+			Power(ref base, ref exponent) => Power(map(base), map(exponent)),
+			Exp(ref term) => Exp(map(term)),
+			NaturalLog(ref term) => NaturalLog(map(term)),
+			Logarithm(ref term, ref base) => Logarithm(map(term), map(base)),
+			SquareRoot(ref term) => SquareRoot(map(term)),
+			NthRoot(ref term, ref index) => NthRoot(map(term), map(index)),
+			AbsoluteValue(ref term) => AbsoluteValue(map(term)),
+			Negation(ref term) => Negation(map(term)),
+			Sinh(ref term) => Sinh(map(term)),
+			Cosh(ref term) => Cosh(map(term)),
+			Tanh(ref term) => Tanh(map(term)),
+			Secant(ref term) => Secant(map(term)),
+			Cosecant(ref term) => Cosecant(map(term)),
+			Cotangent(ref term) => Cotangent(map(term)),
+			Modulo(ref dividend, ref divisor) => Modulo(map(dividend), map(divisor)),
+			Factorial(ref term) => Factorial(map(term)),
+			Summation { ref index, ref from, ref to, ref body } => Summation { index: index.clone(), from: map(from), to: map(to), body: map(body) },
+			IndexedProduct { ref index, ref from, ref to, ref body } => IndexedProduct { index: index.clone(), from: map(from), to: map(to), body: map(body) },
+			Piecewise(ref branches) => Piecewise(branches.iter().map(|(condition, term)| (condition.map(|operand| *map(operand)), *map(term))).collect()),
+			Floor(ref term) => Floor(map(term)),
+			Ceiling(ref term) => Ceiling(map(term)),
+			Sign(ref term) => Sign(map(term)),
+			Maximum(ref terms) => Maximum(terms.iter().map(|term| *map(term)).collect()),
+			Minimum(ref terms) => Minimum(terms.iter().map(|term| *map(term)).collect()),
+			Round(ref term) => Round(map(term)),
+			Truncate(ref term) => Truncate(map(term)),
+			Fractional(ref term) => Fractional(map(term)),
+			Clamp { ref value, ref min, ref max } => Clamp { value: map(value), min: map(min), max: map(max) },
+			PowerInt(ref term, exponent) => PowerInt(map(term), exponent),
+			Derivative { ref body, ref variable } => Derivative { body: map(body), variable: variable.clone() },
+			Atan2 { ref y, ref x } => Atan2 { y: map(y), x: map(x) },
+			Sigmoid(ref term) => Sigmoid(map(term)),
+			ToRadians(ref term) => ToRadians(map(term)),
+			ToDegrees(ref term) => ToDegrees(map(term)),
+			Binomial { ref n, ref k } => Binomial { n: map(n), k: map(k) }
+		}
+	}
+
+	/// Evaluates a term without recursing, so that arbitrarily deep terms can be evaluated without overflowing the stack.
+	///
+	/// Subterms are visited from an explicit work stack in the same order in which a recursive evaluation would visit them, and their values are accumulated on a second stack until the term owning them is applied. Evaluation stops at the first error encountered.
+	pub(crate) fn eval(&self, values: Option<&VariableValues>, mode: AngleMode) -> Result<f64, EvalError> {
+		self.eval_with_epsilon(values, mode, DIVISION_EPSILON)
+	}
+
+	/// Evaluates a term as `Term::eval` does, treating divisors as zero if they are within `epsilon` of it (or exactly zero).
+	pub(crate) fn eval_with_epsilon(&self, values: Option<&VariableValues>, mode: AngleMode, epsilon: f64) -> Result<f64, EvalError> {
+		let mut pending = vec!(Step::Visit(self));
+		let mut results: Vec<f64> = vec!();
+		while let Some(step) = pending.pop() {
+			match step {
+				Step::Visit(term) => {
+					let children = if term.evaluates_own_subterms() { vec!() } else { term.children() };
+					if children.is_empty() {
+						results.push(term.apply_with_epsilon(&[], values, mode, epsilon)?);
+						continue;
+					}
+					pending.push(Step::Apply(term, children.len()));
+					let divisions = matches!(*term, Term::Quotient(_));
+					for (index, child) in children.into_iter().enumerate().rev() {
+						// Divisors are checked as soon as they are evaluated, before any later divisors.
+						if divisions && index > 0 {
+							pending.push(Step::CheckDivisor);
+						}
+						pending.push(Step::Visit(child));
+					}
+				},
+				Step::CheckDivisor => {
+					let divisor = results[results.len() - 1];
+					if divisor == 0.0 || divisor.abs() < epsilon {
+						return Err(EvalError::DivisionByZero);
+					}
+				},
+				Step::Apply(term, count) => {
+					let start = results.len() - count;
+					let value = term.apply_with_epsilon(&results[start..], values, mode, epsilon)?;
+					results.truncate(start);
+					results.push(value);
+				}
+			}
+		}
+		Ok(results[0])
+	}
+
+	/// Evaluates a term bottom-up without recursing (as `Term::eval` does), using `apply` to compute the value of each subterm from the values of its children (see `Term::children`), in order.
+	///
+	/// Terms which evaluate their own subterms (see `Term::evaluates_own_subterms`) are given no values.
+	pub(crate) fn evaluate_postorder<T, E, F: FnMut(&Term, Vec<T>) -> Result<T, E>>(&self, mut apply: F) -> Result<T, E> {
+		// Each term is visited once to schedule its children, and again to apply it.
+		let mut pending = vec!((self, false));
+		let mut results: Vec<T> = vec!();
+		while let Some((term, visited)) = pending.pop() {
+			let children = if term.evaluates_own_subterms() { vec!() } else { term.children() };
+			if visited || children.is_empty() {
+				let operands = results.split_off(results.len() - children.len());
+				results.push(apply(term, operands)?);
+			} else {
+				pending.push((term, true));
+				pending.extend(children.into_iter().rev().map(|child| (child, false)));
+			}
+		}
+		Ok(results.remove(0))
+	}
+
+	/// Computes the value of a term given the values of its children (as `Term::apply` does), first checking that none of the divisors of a quotient are (approximately) zero as `Term::eval` does.
+	pub(crate) fn apply_dividing(&self, operands: &[f64], values: Option<&VariableValues>, mode: AngleMode) -> Result<f64, EvalError> {
+		if matches!(*self, Term::Quotient(_)) && operands.iter().skip(1).any(|divisor| divisor.abs() < DIVISION_EPSILON) {
+			return Err(EvalError::DivisionByZero);
+		}
+		self.apply(operands, values, mode)
+	}
+
+	/// Computes the value of a term given the values of its children (see `Term::children`), in order.
+	pub(crate) fn apply(&self, operands: &[f64], values: Option<&VariableValues>, mode: AngleMode) -> Result<f64, EvalError> {
+		self.apply_with_epsilon(operands, values, mode, DIVISION_EPSILON)
+	}
+
+	/// Computes the value of a term as `Term::apply` does, treating divisors as zero if they are within `epsilon` of it (or exactly zero).
+	fn apply_with_epsilon(&self, operands: &[f64], values: Option<&VariableValues>, mode: AngleMode, epsilon: f64) -> Result<f64, EvalError> {
+		use Term::*;
+		match *self {
+			Constant(value) => Ok(value),
+			Variable(ref variable) => {
+				if let Some(v) = values {
+					if let Some(value) = v.get(&variable.symbol) {
+						Ok(*value)
+					} else {
+						Err(EvalError::UndefinedVariable(variable.symbol.clone()))
+					}
+				} else {
+					Err(EvalError::NoBindings(variable.symbol.clone()))
+				}
+			},
+			Sum(_) => Ok(operands.iter().sum()), // dim sum for a twosome
+			Difference(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("difference".to_string()))?;
+				Ok(rest.iter().fold(*first, |difference, value| difference - value))
+			},
+			Product(_) => Ok(operands.iter().product()),
+			Quotient(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands("quotient".to_string()))?;
+				Ok(rest.iter().fold(*first, |quotient, divisor| quotient / divisor))
+			},
+			Sine(_) => Ok(mode.to_radians(operands[0]).sin()),
+			Cosine(_) => Ok(mode.to_radians(operands[0]).cos()),
+			Tangent(_) => Ok(mode.to_radians(operands[0]).tan()),
+			ArcSine(_) => Ok(mode.convert_radians(operands[0].asin())),
+			ArcCosine(_) => Ok(mode.convert_radians(operands[0].acos())),
+			ArcTangent(_) => Ok(mode.convert_radians(operands[0].atan())),
+			Power(_, _) => Ok(operands[0].powf(operands[1])),
+			Exp(_) => Ok(operands[0].exp()),
+			NaturalLog(_) => {
+				let value = operands[0];
+				if value <= 0.0 {
+					return Err(EvalError::DomainError { function: "ln".to_string(), value });
+				}
+				Ok(value.ln())
+			},
+			Logarithm(_, _) => {
+				let (value, base) = (operands[0], operands[1]);
+				if value <= 0.0 {
+					return Err(EvalError::DomainError { function: "log".to_string(), value });
+				}
+				if base <= 0.0 || base == 1.0 {
+					return Err(EvalError::DomainError { function: "log base".to_string(), value: base });
+				}
+				Ok(value.log(base))
+			},
+			SquareRoot(_) => {
+				let value = operands[0];
+				if value < 0.0 {
+					return Err(EvalError::DomainError { function: "sqrt".to_string(), value });
+				}
+				Ok(value.sqrt())
+			},
+			NthRoot(_, _) => {
+				let (value, index) = (operands[0], operands[1]);
+				if index == 0.0 {
+					return Err(EvalError::DomainError { function: "root index".to_string(), value: index });
+				}
+				if value >= 0.0 {
+					Ok(value.powf(1.0 / index))
+				} else if index.fract() == 0.0 && index % 2.0 != 0.0 {
+					Ok(-(-value).powf(1.0 / index))
+				} else {
+					Err(EvalError::DomainError { function: "root".to_string(), value })
+				}
+			},
+			AbsoluteValue(_) => Ok(operands[0].abs()),
+			Negation(_) => Ok(-operands[0]),
+			Sinh(_) => Ok(operands[0].sinh()),
+			Cosh(_) => Ok(operands[0].cosh()),
+			Tanh(_) => Ok(operands[0].tanh()),
+			Secant(_) => {
+				let cosine = mode.to_radians(operands[0]).cos();
+				if cosine.abs() < POLE_EPSILON {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(1.0 / cosine)
+			},
+			Cosecant(_) => {
+				let sine = mode.to_radians(operands[0]).sin();
+				if sine.abs() < POLE_EPSILON {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(1.0 / sine)
+			},
+			Cotangent(_) => {
+				let angle = mode.to_radians(operands[0]);
+				if angle.sin().abs() < POLE_EPSILON {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(angle.cos() / angle.sin())
+			},
+			Modulo(_, _) => {
+				let (dividend, divisor) = (operands[0], operands[1]);
+				if divisor == 0.0 || divisor.abs() < epsilon {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(dividend.rem_euclid(divisor))
+			},
+			Factorial(_) => {
+				let value = operands[0];
+				if value.fract() != 0.0 {
+					return Ok(gamma(value + 1.0));
+				}
+				if value < 0.0 {
+					return Err(EvalError::DomainError { function: "factorial".to_string(), value });
+				}
+				if value > MAXIMUM_FACTORIAL {
+					return Ok(f64::INFINITY);
+				}
+				Ok((2..=value as u32).map(f64::from).product())
+			},
+			Summation { ref index, ref from, ref to, ref body } => Ok(evaluate_indexed("summation", (index, from, to, body), values, mode, epsilon)?.into_iter().sum()),
+			IndexedProduct { ref index, ref from, ref to, ref body } => Ok(evaluate_indexed("product", (index, from, to, body), values, mode, epsilon)?.into_iter().product()),
+			Pi => Ok(consts::PI),
+			E => Ok(consts::E),
+			Piecewise(ref branches) => {
+				for (condition, term) in branches {
+					if condition.holds(values, mode, epsilon)? {
+						return term.eval_with_epsilon(values, mode, epsilon);
+					}
+				}
+				Err(EvalError::NoMatchingBranch)
+			},
+			Floor(_) => Ok(operands[0].floor()),
+			Ceiling(_) => Ok(operands[0].ceil()),
+			Sign(_) => {
+				if operands[0] == 0.0 {
+					return Ok(0.0);
+				}
+				Ok(operands[0].signum())
+			},
+			Maximum(_) | Minimum(_) => {
+				let (first, rest) = operands.split_first().ok_or_else(|| EvalError::NoOperands(self.function_name().unwrap_or_default().to_string()))?;
+				let maximum = matches!(*self, Maximum(_));
+				Ok(rest.iter().fold(*first, |extreme, &value| if maximum { extreme.max(value) } else { extreme.min(value) }))
+			},
+			Round(_) => Ok(operands[0].round()),
+			Truncate(_) => Ok(operands[0].trunc()),
+			Fractional(_) => Ok(operands[0].fract()),
+			Clamp { .. } => {
+				let (value, min, max) = (operands[0], operands[1], operands[2]);
+				if min > max || min.is_nan() || max.is_nan() {
+					return Err(EvalError::DomainError { function: "clamp bounds".to_string(), value: min });
+				}
+				Ok(value.clamp(min, max))
+			},
+			PowerInt(_, exponent) => {
+				let base = operands[0];
+				if exponent < 0 && (base == 0.0 || base.abs() < epsilon) {
+					return Err(EvalError::DivisionByZero);
+				}
+				Ok(base.powi(exponent))
+			},
+			Derivative { ref body, ref variable } => body.derivative(variable).eval_with_epsilon(values, mode, epsilon),
+			Atan2 { .. } => Ok(mode.convert_radians(operands[0].atan2(operands[1]))),
+			Sigmoid(_) => {
+				// Only ever exponentiate non-positive values, which cannot overflow.
+				let value = operands[0];
+				if value >= 0.0 {
+					Ok(1.0 / (1.0 + (-value).exp()))
+				} else {
+					let exp = value.exp();
+					Ok(exp / (1.0 + exp))
+				}
+			},
+			ToRadians(_) => Ok(operands[0].to_radians()),
+			ToDegrees(_) => Ok(operands[0].to_degrees()),
+			Binomial { .. } => {
+				let (n, k) = (operands[0], operands[1]);
+				if n.fract() != 0.0 || n < 0.0 {
+					return Err(EvalError::DomainError { function: "binomial".to_string(), value: n });
+				}
+				if k.fract() != 0.0 || k < 0.0 || k > n {
+					return Err(EvalError::DomainError { function: "binomial".to_string(), value: k });
+				}
+				// Each partial product is itself a binomial coefficient, (n - k + i) choose i.
+				let k = k.min(n - k);
+				let (mut coefficient, mut i) = (1f64, 1.0);
+				while i <= k && coefficient.is_finite() {
+					coefficient = coefficient * (n - k + i) / i;
+					i += 1.0;
+				}
+				Ok(coefficient)
+			}
+		}
+	}
+
+}
+
+impl Default for Term {
+	/// The default term is the additive identity, `Term::zero()`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// assert_eq!(Term::default(), Term::zero());
+	/// assert_eq!(Term::default().reduce(), Ok(0.0));
+	/// ```
+	fn default() -> Self {
+		Term::zero()
+	}
+}
+
+impl From<f64> for Term {
+	/// Numbers may be converted into constant terms.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let c: Term = 3.0.into();
+	/// assert_eq!(c, Term::Constant(3.0));
+	/// assert!((c.reduce().unwrap() - 3.0).abs() < 0.00001);
+	/// ```
+	fn from(value: f64) -> Self {
+		Term::Constant(value)
+	}
+}
+
+impl From<Variable> for Term {
+	/// Variables may be converted into variable terms.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	/// use std::collections::HashMap;
+	///
+	/// let x: Term = Variable::named("x").into();
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 4.0);
+	/// assert!((x.evaluate(&values).unwrap() - 4.0).abs() < 0.00001);
+	/// ```
+	fn from(variable: Variable) -> Self {
+		Term::Variable(variable)
+	}
+}
+
+impl From<char> for Term {
+	/// Characters may be converted directly into variable terms.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	/// use std::collections::HashMap;
+	///
+	/// let s = Term::from('x') + Term::from(2.0);
+	/// let mut values = HashMap::new();
+	/// values.insert("x".to_string(), 4.0);
+	/// assert!((s.evaluate(&values).unwrap() - 6.0).abs() < 0.00001);
+	/// ```
+	fn from(symbol: char) -> Self {
+		Term::Variable(Variable::named(symbol))
+	}
+}
+
+impl<'b> Add<&'b Term> for &Term {
+
+	type Output = Term;
+
+	fn add(self, another: &'b Term) -> Term {
+		self.clone() + another.clone()
+	}
+}
+
 impl Add for Term {
 
 	type Output = Term;
 
+	/// Terms may be added with the `+` operator, producing a `Term::Sum`.
+	///
+	/// Sums are flattened rather than nested: adding to (or adding) an existing `Term::Sum` extends its operands.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let s = Term::Constant(1.0) + Term::Constant(2.0) + Term::Constant(3.0);
+	/// match s {
+	///     Term::Sum(ref terms) => assert_eq!(terms.len(), 3),
+	///     _ => panic!("Expected a sum.")
+	/// }
+	/// assert!((s.reduce().unwrap() - 6.0).abs() < 0.00001);
+	/// ```
 	fn add(self, another: Term) -> Term {
-		&self + &another
+		let mut terms = match self {
+			Term::Sum(terms) => terms,
+			term => vec!(term)
+		};
+		match another {
+			Term::Sum(others) => terms.extend(others),
+			term => terms.push(term)
+		};
+		Term::Sum(terms)
+	}
+}
+
+impl AddAssign for Term {
+	/// Terms may be added in place with the `+=` operator, producing the same (flat) `Term::Sum` as `+` would.
+	///
+	/// If the term is already a `Term::Sum`, its operands are extended in place rather than rebuilt.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let y = Term::Variable(Variable::named('y'));
+	/// let mut s = Term::zero();
+	/// s += x.clone();
+	/// s += y.clone();
+	/// assert_eq!(s, Term::Sum(vec!(Term::zero(), x.clone(), y.clone())));
+	/// assert_eq!(s, Term::zero() + x + y);
+	/// ```
+	fn add_assign(&mut self, another: Term) {
+		if let Term::Sum(ref mut terms) = *self {
+			match another {
+				Term::Sum(others) => terms.extend(others),
+				term => terms.push(term)
+			}
+			return;
+		}
+		let term = mem::take(self);
+		*self = term + another;
 	}
 }
 
-impl<'a, 'b> Sub<&'b Term> for &'a Term {
+impl<'b> Sub<&'b Term> for &Term {
 
 	type Output = Term;
 
 	fn sub(self, another: &'b Term) -> Term {
-		Term::Difference(vec!(self.clone(), another.clone()))
+		self.clone() - another.clone()
 	}
 }
 
@@ -334,17 +2152,43 @@ impl Sub for Term {
 
 	type Output = Term;
 
+	/// Terms may be subtracted with the `-` operator, producing a `Term::Difference`.
+	///
+	/// Subtracting from an existing `Term::Difference` appends to its operands, so `a - b - c` is `Term::Difference(vec!(a, b, c))`. The right-hand side is never flattened, since subtraction is not associative.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let d = Term::Constant(10.0) - Term::Constant(3.0);
+	/// assert!((d.reduce().unwrap() - 7.0).abs() < 0.00001);
+	///
+	/// let d = Term::Constant(10.0) - Term::Constant(3.0) - Term::Constant(2.0);
+	/// match d {
+	///     Term::Difference(ref terms) => assert_eq!(terms.len(), 3),
+	///     _ => panic!("Expected a difference.")
+	/// }
+	/// assert!((d.reduce().unwrap() - 5.0).abs() < 0.00001);
+	///
+	/// let e = Term::Constant(10.0) - (Term::Constant(3.0) - Term::Constant(2.0));
+	/// assert!((e.reduce().unwrap() - 9.0).abs() < 0.00001);
+	/// ```
 	fn sub(self, another: Term) -> Term {
-		&self - &another
+		let mut terms = match self {
+			Term::Difference(terms) if !terms.is_empty() => terms,
+			term => vec!(term)
+		};
+		terms.push(another);
+		Term::Difference(terms)
 	}
 }
 
-impl<'a, 'b> Mul<&'b Term> for &'a Term {
+impl<'b> Mul<&'b Term> for &Term {
 
 	type Output = Term;
 
 	fn mul(self, another: &'b Term) -> Term {
-		Term::Product(vec!(self.clone(), another.clone()))
+		self.clone() * another.clone()
 	}
 }
 
@@ -352,17 +2196,74 @@ impl Mul for Term {
 
 	type Output = Term;
 
+	/// Terms may be multiplied with the `*` operator, producing a `Term::Product`.
+	///
+	/// Products are flattened rather than nested: multiplying a `Term::Product` by another concatenates their operands.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let p = Term::Constant(6.0) * Term::Constant(7.0);
+	/// assert!((p.reduce().unwrap() - 42.0).abs() < 0.00001);
+	///
+	/// let a = Term::Constant(2.0) * Term::Constant(3.0);
+	/// let b = Term::Constant(4.0) * Term::Constant(5.0);
+	/// let c = a * b;
+	/// match c {
+	///     Term::Product(ref terms) => assert_eq!(terms.len(), 4),
+	///     _ => panic!("Expected a product.")
+	/// }
+	/// assert!((c.reduce().unwrap() - 120.0).abs() < 0.00001);
+	/// ```
 	fn mul(self, another: Term) -> Term {
-		&self * &another
+		let mut terms = match self {
+			Term::Product(terms) => terms,
+			term => vec!(term)
+		};
+		match another {
+			Term::Product(others) => terms.extend(others),
+			term => terms.push(term)
+		};
+		Term::Product(terms)
+	}
+}
+
+impl MulAssign for Term {
+	/// Terms may be multiplied in place with the `*=` operator, producing the same (flat) `Term::Product` as `*` would.
+	///
+	/// If the term is already a `Term::Product`, its operands are extended in place rather than rebuilt.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Term, Variable};
+	///
+	/// let x = Term::Variable(Variable::named('x'));
+	/// let mut p = Term::Constant(2.0);
+	/// p *= x.clone();
+	/// p *= Term::Constant(3.0) * x.clone();
+	/// assert_eq!(p, Term::Product(vec!(Term::Constant(2.0), x.clone(), Term::Constant(3.0), x.clone())));
+	/// assert_eq!(p, Term::Constant(2.0) * x.clone() * (Term::Constant(3.0) * x));
+	/// ```
+	fn mul_assign(&mut self, another: Term) {
+		if let Term::Product(ref mut terms) = *self {
+			match another {
+				Term::Product(others) => terms.extend(others),
+				term => terms.push(term)
+			}
+			return;
+		}
+		let term = mem::replace(self, Term::one());
+		*self = term * another;
 	}
 }
 
-impl<'a, 'b> Div<&'b Term> for &'a Term {
+impl<'b> Div<&'b Term> for &Term {
 
 	type Output = Term;
 
 	fn div(self, another: &'b Term) -> Term {
-		Term::Quotient(vec!(self.clone(), another.clone()))
+		self.clone() / another.clone()
 	}
 }
 
@@ -370,7 +2271,147 @@ impl Div for Term {
 
 	type Output = Term;
 
+	/// Terms may be divided with the `/` operator, producing a two-element `Term::Quotient`.
+	///
+	/// Unlike sums and products, quotients are never flattened, so `a / b / c` nests.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let q = Term::Constant(20.0) / Term::Constant(4.0);
+	/// match q {
+	///     Term::Quotient(ref terms) => assert_eq!(terms.len(), 2),
+	///     _ => panic!("Expected a quotient.")
+	/// }
+	/// assert!((q.reduce().unwrap() - 5.0).abs() < 0.00001);
+	/// ```
 	fn div(self, another: Term) -> Term {
-		&self / &another
+		Term::Quotient(vec!(self, another))
+	}
+}
+
+impl iter::Sum for Term {
+	/// Iterators of terms may be summed, producing a (flat) `Term::Sum` as with the `+` operator.
+	///
+	/// The sum of an empty iterator is `Term::zero()`, and the sum of a single term is that term.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let terms = vec!(Term::Constant(1.0), Term::Constant(2.0), Term::Constant(3.0));
+	/// let s = terms.into_iter().sum::<Term>();
+	/// assert_eq!(s, Term::Sum(vec!(Term::Constant(1.0), Term::Constant(2.0), Term::Constant(3.0))));
+	/// assert!((s.reduce().unwrap() - 6.0).abs() < 0.00001);
+	///
+	/// assert_eq!(Vec::new().into_iter().sum::<Term>(), Term::zero());
+	/// ```
+	fn sum<I: Iterator<Item = Term>>(mut terms: I) -> Term {
+		match terms.next() {
+			Some(first) => terms.fold(first, |sum, term| sum + term),
+			None => Term::zero()
+		}
+	}
+}
+
+impl iter::Product for Term {
+	/// Iterators of terms may be multiplied together, producing a (flat) `Term::Product` as with the `*` operator.
+	///
+	/// The product of an empty iterator is `Term::one()`, and the product of a single term is that term.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let terms = vec!(Term::Constant(2.0), Term::Constant(3.0), Term::Constant(4.0));
+	/// let p = terms.into_iter().product::<Term>();
+	/// assert_eq!(p, Term::Product(vec!(Term::Constant(2.0), Term::Constant(3.0), Term::Constant(4.0))));
+	/// assert!((p.reduce().unwrap() - 24.0).abs() < 0.00001);
+	///
+	/// assert_eq!(Vec::new().into_iter().product::<Term>(), Term::one());
+	/// ```
+	fn product<I: Iterator<Item = Term>>(mut terms: I) -> Term {
+		match terms.next() {
+			Some(first) => terms.fold(first, |product, term| product * term),
+			None => Term::one()
+		}
+	}
+}
+
+impl Neg for Term {
+
+	type Output = Term;
+
+	/// Terms may be negated with the unary `-` operator, which wraps them in `Term::Negation`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Constant(4.0);
+	/// let b = -a;
+	/// assert!(match b { Term::Negation(_) => true, _ => false });
+	/// assert!((b.reduce().unwrap() + 4.0).abs() < 0.00001);
+	/// ```
+	fn neg(self) -> Term {
+		Term::Negation(Box::new(self))
+	}
+}
+
+impl Neg for &Term {
+
+	type Output = Term;
+
+	/// Borrowed terms may also be negated, in which case the operand is cloned.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Term;
+	///
+	/// let a = Term::Constant(4.0);
+	/// let b = -&a;
+	/// assert!((a.reduce().unwrap() - 4.0).abs() < 0.00001);
+	/// assert!((b.reduce().unwrap() + 4.0).abs() < 0.00001);
+	/// ```
+	fn neg(self) -> Term {
+		-self.clone()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Term;
+	use std::collections::HashMap;
+
+	#[test]
+	fn evaluate_many_matches_evaluate() {
+		let terms = ["x * y - sin(x)", "sqrt(x - y)", "sum(i * x, i, 1, 3)", "2 + 3"];
+		let sets = [(0.0, 1.0), (2.0, -1.0), (1.5, 0.5)].iter().map(|&(x, y)| {
+			let mut values = HashMap::new();
+			values.insert("x".to_string(), x);
+			values.insert("y".to_string(), y);
+			values
+		}).collect::<Vec<_>>();
+		for term in terms.iter() {
+			let term: Term = term.parse().unwrap();
+			let expected = sets.iter().map(|values| term.evaluate(values)).collect::<Vec<_>>();
+			assert_eq!(term.evaluate_many(&sets), expected);
+		}
+		assert!(Term::Constant(1.0).evaluate_many(&[]).is_empty());
+	}
+
+	#[test]
+	fn deeply_nested_evaluation() {
+		let mut term = Term::Constant(0.0);
+		for _ in 0..100_000 {
+			term = Term::Sum(vec!(term, Term::Constant(1.0)));
+		}
+		assert!((term.reduce().unwrap() - 100_000.0).abs() < 0.00001);
+		assert_eq!(term.depth(), 100_001);
+		// Dropping the term normally would recurse once per level, so take it apart one level at a time.
+		while let Term::Sum(mut terms) = term {
+			term = terms.swap_remove(0);
+		}
 	}
 }
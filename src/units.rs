@@ -0,0 +1,225 @@
+use error::EvalError;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use term::{Term, VariableValues};
+
+/// The physical dimension of a quantity, as the exponents of the seven SI base dimensions.
+///
+/// Dimensions are combined by multiplication and division, which add and subtract their exponents. The default dimension (with every exponent zero) is that of a pure number.
+///
+/// # Examples
+/// ```
+/// use cassie::Dimension;
+///
+/// let velocity = Dimension::LENGTH / Dimension::TIME;
+/// assert_eq!(velocity, Dimension { length: 1, time: -1, ..Dimension::DIMENSIONLESS });
+/// assert_eq!(&format!("{}", velocity * Dimension::MASS), "m kg s^-1");
+/// assert_eq!(velocity / velocity, Dimension::DIMENSIONLESS);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dimension {
+	/// The exponent of length (measured in metres).
+	pub length: i32,
+	/// The exponent of mass (measured in kilograms).
+	pub mass: i32,
+	/// The exponent of time (measured in seconds).
+	pub time: i32,
+	/// The exponent of electric current (measured in amperes).
+	pub current: i32,
+	/// The exponent of thermodynamic temperature (measured in kelvins).
+	pub temperature: i32,
+	/// The exponent of amount of substance (measured in moles).
+	pub amount: i32,
+	/// The exponent of luminous intensity (measured in candelas).
+	pub luminosity: i32
+}
+
+impl Dimension {
+	/// The dimension of pure numbers.
+	pub const DIMENSIONLESS: Dimension = Dimension { length: 0, mass: 0, time: 0, current: 0, temperature: 0, amount: 0, luminosity: 0 };
+	/// The dimension of length.
+	pub const LENGTH: Dimension = Dimension { length: 1, ..Dimension::DIMENSIONLESS };
+	/// The dimension of mass.
+	pub const MASS: Dimension = Dimension { mass: 1, ..Dimension::DIMENSIONLESS };
+	/// The dimension of time.
+	pub const TIME: Dimension = Dimension { time: 1, ..Dimension::DIMENSIONLESS };
+	/// The dimension of electric current.
+	pub const CURRENT: Dimension = Dimension { current: 1, ..Dimension::DIMENSIONLESS };
+	/// The dimension of thermodynamic temperature.
+	pub const TEMPERATURE: Dimension = Dimension { temperature: 1, ..Dimension::DIMENSIONLESS };
+	/// The dimension of amount of substance.
+	pub const AMOUNT: Dimension = Dimension { amount: 1, ..Dimension::DIMENSIONLESS };
+	/// The dimension of luminous intensity.
+	pub const LUMINOSITY: Dimension = Dimension { luminosity: 1, ..Dimension::DIMENSIONLESS };
+
+	/// Whether this is the dimension of pure numbers.
+	pub fn is_dimensionless(&self) -> bool {
+		*self == Dimension::DIMENSIONLESS
+	}
+
+	/// Combines two dimensions exponent by exponent.
+	fn combine<F: Fn(i32, i32) -> i32>(self, other: Dimension, f: F) -> Dimension {
+		Dimension {
+			length: f(self.length, other.length),
+			mass: f(self.mass, other.mass),
+			time: f(self.time, other.time),
+			current: f(self.current, other.current),
+			temperature: f(self.temperature, other.temperature),
+			amount: f(self.amount, other.amount),
+			luminosity: f(self.luminosity, other.luminosity)
+		}
+	}
+}
+
+impl Mul for Dimension {
+	type Output = Dimension;
+	/// Multiplying dimensions adds their exponents.
+	fn mul(self, other: Dimension) -> Dimension {
+		self.combine(other, |a, b| a + b)
+	}
+}
+
+impl Div for Dimension {
+	type Output = Dimension;
+	/// Dividing dimensions subtracts their exponents.
+	fn div(self, other: Dimension) -> Dimension {
+		self.combine(other, |a, b| a - b)
+	}
+}
+
+impl fmt::Display for Dimension {
+	/// Dimensions are displayed as products of the symbols of the corresponding SI base units (such as `m kg s^-2`), or as `1` if dimensionless.
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		if self.is_dimensionless() {
+			return write!(f, "1");
+		}
+		let units = [
+			("m", self.length), ("kg", self.mass), ("s", self.time), ("A", self.current),
+			("K", self.temperature), ("mol", self.amount), ("cd", self.luminosity)
+		];
+		let units = units.iter().filter(|&&(_, exponent)| exponent != 0).map(|&(symbol, exponent)| match exponent {
+			1 => symbol.to_string(),
+			_ => format!("{}^{}", symbol, exponent)
+		}).collect::<Vec<_>>();
+		write!(f, "{}", units.join(" "))
+	}
+}
+
+/// A term carrying a physical dimension, for dimensional analysis.
+///
+/// Quantities are combined with the arithmetic operators: multiplication and division combine the dimensions of their operands (see `Dimension`), while addition and subtraction require them to match and produce an error otherwise. Evaluation is that of the underlying term.
+///
+/// # Examples
+/// ```
+/// use cassie::{Dimension, Quantity, Term};
+/// use std::collections::HashMap;
+///
+/// let a = Quantity::new(Term::from('a'), Dimension::LENGTH);
+/// let b = Quantity::new(Term::from('b'), Dimension::LENGTH);
+/// let t = Quantity::new(Term::from('t'), Dimension::TIME);
+///
+/// let total = (a.clone() + b).unwrap();
+/// assert_eq!(total.dimension(), Dimension::LENGTH);
+/// assert!((a.clone() + t.clone()).is_err());
+///
+/// let speed = total / t;
+/// assert_eq!(speed.dimension(), Dimension::LENGTH / Dimension::TIME);
+/// assert!((speed.clone() + a).is_err());
+///
+/// let mut values = HashMap::new();
+/// values.insert("a".to_string(), 3.0);
+/// values.insert("b".to_string(), 5.0);
+/// values.insert("t".to_string(), 2.0);
+/// assert_eq!(speed.evaluate(&values), Ok(4.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantity {
+	term: Term,
+	dimension: Dimension
+}
+
+impl Quantity {
+	/// Creates a quantity with the value of `term` and the dimension `dimension`.
+	pub fn new(term: Term, dimension: Dimension) -> Self {
+		Self { term, dimension }
+	}
+	/// Creates a dimensionless quantity with the value of `term`.
+	pub fn dimensionless(term: Term) -> Self {
+		Self::new(term, Dimension::DIMENSIONLESS)
+	}
+	/// The term giving the value of a quantity.
+	pub fn term(&self) -> &Term {
+		&self.term
+	}
+	/// The dimension of a quantity.
+	pub fn dimension(&self) -> Dimension {
+		self.dimension
+	}
+	/// Discards the dimension of a quantity, producing the term giving its value.
+	pub fn into_term(self) -> Term {
+		self.term
+	}
+	/// Evaluates a quantity to the numerical value of its term (see `Term::evaluate`).
+	pub fn evaluate(&self, values: &VariableValues) -> Result<f64, EvalError> {
+		self.term.evaluate(values)
+	}
+	/// Checks that two quantities have the same dimension, as required to combine them by `operation`.
+	fn check_dimensions(&self, other: &Quantity, operation: &str) -> Result<(), String> {
+		if self.dimension == other.dimension {
+			Ok(())
+		} else {
+			Err(format!("Cannot {} quantities of different dimensions ({} and {}).", operation, self.dimension, other.dimension))
+		}
+	}
+}
+
+impl Add for Quantity {
+	type Output = Result<Quantity, String>;
+	/// Quantities of the same dimension may be added; adding quantities of different dimensions is an error.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Dimension, Quantity, Term};
+	///
+	/// let length = Quantity::new(Term::Constant(2.0), Dimension::LENGTH);
+	/// let time = Quantity::new(Term::Constant(3.0), Dimension::TIME);
+	/// assert_eq!(length.clone() + time, Err("Cannot add quantities of different dimensions (m and s).".to_string()));
+	/// ```
+	fn add(self, other: Quantity) -> Result<Quantity, String> {
+		self.check_dimensions(&other, "add")?;
+		Ok(Quantity::new(self.term + other.term, self.dimension))
+	}
+}
+
+impl Sub for Quantity {
+	type Output = Result<Quantity, String>;
+	/// Quantities of the same dimension may be subtracted; subtracting quantities of different dimensions is an error.
+	fn sub(self, other: Quantity) -> Result<Quantity, String> {
+		self.check_dimensions(&other, "subtract")?;
+		Ok(Quantity::new(self.term - other.term, self.dimension))
+	}
+}
+
+impl Mul for Quantity {
+	type Output = Quantity;
+	/// Quantities of any dimensions may be multiplied, adding the exponents of their dimensions.
+	fn mul(self, other: Quantity) -> Quantity {
+		Quantity::new(self.term * other.term, self.dimension * other.dimension)
+	}
+}
+
+impl Div for Quantity {
+	type Output = Quantity;
+	/// Quantities of any dimensions may be divided, subtracting the exponents of their dimensions.
+	fn div(self, other: Quantity) -> Quantity {
+		Quantity::new(self.term / other.term, self.dimension / other.dimension)
+	}
+}
+
+impl Neg for Quantity {
+	type Output = Quantity;
+	/// Negating a quantity preserves its dimension.
+	fn neg(self) -> Quantity {
+		Quantity::new(-self.term, self.dimension)
+	}
+}
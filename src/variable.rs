@@ -4,24 +4,140 @@ use std::fmt;
 /// A variable represents a value which is arbitrary or unknown.
 ///
 /// The assumption that this object will eventually be assigned a meaningful value is the basis of algebraic manipulation.
-#[derive(Clone, PartialEq)]
+///
+/// Variable symbols may be any non-empty string without whitespace or operators (`+-*/^()`) which does not begin with a digit, such as `x`, `φ`, `vel`, or `theta_1`.
+///
+/// A variable may also carry an `Assumption` about the values it can take, as a hint for algebraic manipulation; assumptions are not checked or otherwise used during evaluation. Variables with the same symbol but different assumptions are considered distinct.
+///
+/// Variables are ordered by their symbols, compared lexicographically by Unicode code point (so all Latin letters precede all Greek letters), and then by their assumptions.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(from = "SerializedVariable", into = "SerializedVariable"))]
 pub struct Variable {
-	pub symbol: char
+	pub symbol: String,
+	pub assumption: Option<Assumption>
+}
+
+/// A known property of the values a variable can take.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Assumption {
+	/// The variable is (real and) strictly greater than zero.
+	Positive,
+	/// The variable is (real and) greater than or equal to zero.
+	NonNegative,
+	/// The variable is an integer.
+	Integer,
+	/// The variable is real.
+	Real
+}
+
+/// The serialized form of a variable: just its symbol if it carries no assumption.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum SerializedVariable {
+	Symbol(String),
+	Assumed {
+		symbol: String,
+		assumption: Assumption
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedVariable> for Variable {
+	fn from(variable: SerializedVariable) -> Self {
+		match variable {
+			SerializedVariable::Symbol(symbol) => Variable::named(symbol),
+			SerializedVariable::Assumed { symbol, assumption } => Variable::assuming(symbol, assumption)
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl From<Variable> for SerializedVariable {
+	fn from(variable: Variable) -> Self {
+		match variable.assumption {
+			None => SerializedVariable::Symbol(variable.symbol),
+			Some(assumption) => SerializedVariable::Assumed { symbol: variable.symbol, assumption }
+		}
+	}
 }
 
 impl Variable {
 	/// Creates a variable with associated symbol `symbol`.
 	///
+	/// Both characters and strings may be used as symbols. The variable carries no assumption.
+	///
 	/// # Examples
 	/// ```
 	/// use cassie::Variable;
 	/// let x = Variable::named('x');
-	/// assert_eq!(x.symbol, 'x');
+	/// assert_eq!(x.symbol, "x");
+	/// assert_eq!(x.assumption, None);
 	/// let v = Variable::named('ν');
-	/// assert_eq!(v.symbol, 'ν');
+	/// assert_eq!(v.symbol, "ν");
+	/// let vel = Variable::named("vel");
+	/// assert_eq!(vel.symbol, "vel");
+	/// ```
+	pub fn named<S: Into<String>>(symbol: S) -> Self {
+		Self { symbol: symbol.into(), assumption: None }
+	}
+	/// Creates a variable with associated symbol `symbol`, assumed to satisfy `assumption`.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Assumption, Variable};
+	/// let n = Variable::assuming('n', Assumption::Integer);
+	/// assert_eq!(n.symbol, "n");
+	/// assert_eq!(n.assumption, Some(Assumption::Integer));
+	/// assert!(n != Variable::named('n'));
 	/// ```
-	pub fn named(symbol: char) -> Self {
-		Self { symbol }
+	pub fn assuming<S: Into<String>>(symbol: S, assumption: Assumption) -> Self {
+		Self { symbol: symbol.into(), assumption: Some(assumption) }
+	}
+	/// Creates a variable assumed to be positive.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Assumption, Variable};
+	/// let x = Variable::positive('x');
+	/// assert_eq!(x.assumption, Some(Assumption::Positive));
+	/// ```
+	pub fn positive<S: Into<String>>(symbol: S) -> Self {
+		Self::assuming(symbol, Assumption::Positive)
+	}
+	/// Creates a variable assumed to be non-negative.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Assumption, Variable};
+	/// let r = Variable::non_negative('r');
+	/// assert_eq!(r.assumption, Some(Assumption::NonNegative));
+	/// ```
+	pub fn non_negative<S: Into<String>>(symbol: S) -> Self {
+		Self::assuming(symbol, Assumption::NonNegative)
+	}
+	/// Creates a variable assumed to be an integer.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Assumption, Variable};
+	/// let k = Variable::integer('k');
+	/// assert_eq!(k.assumption, Some(Assumption::Integer));
+	/// ```
+	pub fn integer<S: Into<String>>(symbol: S) -> Self {
+		Self::assuming(symbol, Assumption::Integer)
+	}
+	/// Creates a variable assumed to be real.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::{Assumption, Variable};
+	/// let t = Variable::real('t');
+	/// assert_eq!(t.assumption, Some(Assumption::Real));
+	/// ```
+	pub fn real<S: Into<String>>(symbol: S) -> Self {
+		Self::assuming(symbol, Assumption::Real)
 	}
 	/// An alias for `Variable::named`.
 	/// 
@@ -29,12 +145,25 @@ impl Variable {
 	/// ```
 	/// use cassie::Variable;
 	/// let x = Variable::new('x');
-	/// assert_eq!(x.symbol, 'x');
-	/// let v = Variable::new('ν');
-	/// assert_eq!(v.symbol, 'ν');
+	/// assert_eq!(x.symbol, "x");
+	/// let v = Variable::new("θ_0");
+	/// assert_eq!(v.symbol, "θ_0");
+	/// ```
+	pub fn new<S: Into<String>>(symbol: S) -> Self {
+		Self::named(symbol)
+	}
+}
+
+impl Default for Variable {
+	/// The default variable is `x`, the conventional unknown, with no assumption.
+	///
+	/// # Examples
+	/// ```
+	/// use cassie::Variable;
+	/// assert_eq!(Variable::default(), Variable::named('x'));
 	/// ```
-	pub fn new(c: char) -> Self {
-		Self::named(c)
+	fn default() -> Self {
+		Variable::named('x')
 	}
 }
 
@@ -59,25 +188,59 @@ impl FromStr for Variable {
 	/// # Examples
 	/// ```
 	/// use cassie::Variable;
-	/// assert_eq!(Variable { symbol: 'x' }, "x".parse::<Variable>().unwrap());
-	/// assert_eq!(Variable { symbol: 'Γ' }, "Γ".parse::<Variable>().unwrap());
-	/// // Note that variable names must comprise exactly one character.
-	///	assert!("".parse::<Variable>().is_err());
-	///	assert!("xy".parse::<Variable>().is_err());
+	/// assert_eq!(Variable::named('x'), "x".parse::<Variable>().unwrap());
+	/// assert_eq!(Variable::named('Γ'), "Γ".parse::<Variable>().unwrap());
+	/// assert_eq!(Variable::named("vel"), "vel".parse::<Variable>().unwrap());
+	/// assert_eq!(Variable::named("x_1"), "x_1".parse::<Variable>().unwrap());
+	/// // Note that variable names must be non-empty and may not contain whitespace.
+	/// assert!("".parse::<Variable>().is_err());
+	/// assert!("x y".parse::<Variable>().is_err());
+	/// // Nor may they begin with a digit or contain operators, so as not to be confused with numbers or compound terms.
+	/// assert!("5".parse::<Variable>().is_err());
+	/// assert!("2x".parse::<Variable>().is_err());
+	/// assert!("x+y".parse::<Variable>().is_err());
+	/// assert!("(".parse::<Variable>().is_err());
 	/// ```
 	fn from_str(s: &str) -> Result<Variable, Self::Err> {
-		let chars = s.chars().collect::<Vec<_>>();
-		match chars.len() {
-			0 => Err(format!("Variables must be one character long (none given).")),
-			1 => Ok(Variable { symbol: chars[0] }),
-			_ => Err(format!("Variables cannot be longer than one character ({} found).", chars.len()))
+		if s.is_empty() {
+			Err("Variables must be at least one character long (none given).".to_string())
+		} else if s.chars().any(char::is_whitespace) {
+			Err(format!("Variables cannot contain whitespace (\"{}\" given).", s))
+		} else if s.starts_with(|c: char| c.is_ascii_digit()) {
+			Err(format!("Variables cannot begin with a digit (\"{}\" given).", s))
+		} else if let Some(operator) = s.chars().find(|c| "+-*/^()".contains(*c)) {
+			Err(format!("Variables cannot contain the operator '{}' (\"{}\" given).", operator, s))
+		} else {
+			Ok(Variable::named(s))
 		}
 	}
 }
 
 #[cfg(test)]
 mod tests {
+	use super::Variable;
+	use std::collections::HashSet;
+
 	#[test]
 	fn it_works() {
 	}
+
+	#[test]
+	fn hash_set_membership() {
+		let mut variables = HashSet::new();
+		assert!(variables.insert(Variable::named('x')));
+		assert!(variables.insert(Variable::named("vel")));
+		assert!(!variables.insert(Variable::named("x")));
+		assert_eq!(variables.len(), 2);
+		assert!(variables.contains(&Variable::named('x')));
+		assert!(!variables.contains(&Variable::named('y')));
+	}
+
+	#[test]
+	fn ordering() {
+		let mut variables = [Variable::named('β'), Variable::named('y'), Variable::named("xy"), Variable::named('α'), Variable::named('X'), Variable::named('x')];
+		variables.sort();
+		let symbols = variables.iter().map(|variable| variable.symbol.as_str()).collect::<Vec<_>>();
+		assert_eq!(symbols, vec!("X", "x", "xy", "y", "α", "β"));
+	}
 }
\ No newline at end of file
@@ -0,0 +1,31 @@
+#![cfg(feature = "serde")]
+
+extern crate cassie;
+extern crate serde_json;
+
+use cassie::{Assumption, Term, Variable};
+
+#[test]
+fn round_trip() {
+	let x = Term::Variable(Variable::named('x'));
+	let term = Term::Sine(Box::new(x.clone() * Term::Constant(2.0))) + Term::Power(Box::new(x), Box::new(Term::Constant(0.5)));
+	let json = serde_json::to_string(&term).unwrap();
+	let parsed: Term = serde_json::from_str(&json).unwrap();
+	assert_eq!(parsed, term);
+}
+
+#[test]
+fn variable_as_symbol() {
+	let json = serde_json::to_string(&Variable::named("vel")).unwrap();
+	assert_eq!(json, "\"vel\"");
+	let parsed: Variable = serde_json::from_str(&json).unwrap();
+	assert_eq!(parsed, Variable::named("vel"));
+}
+
+#[test]
+fn variable_with_assumption() {
+	let json = serde_json::to_string(&Variable::positive('x')).unwrap();
+	assert_eq!(json, "{\"symbol\":\"x\",\"assumption\":\"Positive\"}");
+	let parsed: Variable = serde_json::from_str(&json).unwrap();
+	assert_eq!(parsed.assumption, Some(Assumption::Positive));
+}